@@ -0,0 +1,39 @@
+//! Plays a game with the options and every choice taken from the fuzzer's input, checking the
+//! engine's invariants after every action.
+//!
+//! Run with `cargo fuzz run actions` from the top of the repo.
+
+#![no_main]
+
+#[path = "../../tests/common/mod.rs"]
+mod common;
+
+use common::{Chooser, play, random_options};
+use libfuzzer_sys::fuzz_target;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Makes choices from raw bytes. Once they run out, every choice is 0, which always lets the game
+/// finish.
+struct Bytes<'a>(&'a [u8]);
+
+impl Chooser for Bytes<'_> {
+    fn next(&mut self) -> u64 {
+        let len = self.0.len().min(8);
+        let (head, rest) = self.0.split_at(len);
+        let mut buf = [0; 8];
+
+        buf[..len].copy_from_slice(head);
+        self.0 = rest;
+
+        u64::from_le_bytes(buf)
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let mut bytes = Bytes(data);
+    let seed = bytes.next();
+    let options = random_options(&mut StdRng::seed_from_u64(seed), seed);
+
+    play(options, &mut bytes);
+});
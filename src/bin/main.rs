@@ -1,20 +1,107 @@
 //! Main game running code.
 
-use starlanes::starlanes::StarLanes;
+use starlanes::starlanes::{
+    DividendSchedule, MAX_COMPANIES, MAX_PLAYERS, MergeBonus, MergeRule, PriceCurve, StarLanes,
+    StarLanesOptions,
+};
 
 mod ui;
 
 /// Command line options.
 struct UserInterfaceOptions {
+    seed: Option<u64>,
+    player_count: Option<usize>,
+    names: Option<Vec<String>>,
+    computer: Option<Vec<usize>>,
+    map_size: Option<(usize, usize)>,
+    star_probability: Option<f32>,
+    company_count: Option<usize>,
+    starting_cash: Option<i64>,
+    candidate_move_count: Option<usize>,
+    persistent_hands: Option<bool>,
+    allow_pass: Option<bool>,
+    reroll_fee: Option<i64>,
     wizard_mode: Option<bool>,
     max_turns: Option<usize>,
+    loans: Option<bool>,
+    loan_margin_percentage: Option<f32>,
+    loan_call_percentage: Option<f32>,
+    loan_interest_percentage: Option<f32>,
+    random_events: Option<bool>,
+    pirate_raid_probability: Option<f32>,
+    pirate_raid_percentage: Option<f32>,
+    trade_boom_probability: Option<f32>,
+    trade_boom_percentage: Option<f32>,
+    supernova_probability: Option<f32>,
+    subsidy_probability: Option<f32>,
+    subsidy_per_share: Option<i64>,
+    price_curve: Option<PriceCurve>,
+    limited_shares: Option<bool>,
+    merge_bonus: Option<MergeBonus>,
+    merge_rule: Option<MergeRule>,
+    safe_company_size: Option<u64>,
+    dividend_schedule: Option<DividendSchedule>,
+    dividend_percentage_per_size: Option<f32>,
+    suspend_dividends_after_merge: Option<bool>,
+    max_rounds: Option<usize>,
+    target_net_worth: Option<i64>,
+    board_coverage_percentage: Option<f32>,
+    end_when_all_safe: Option<bool>,
+    team_size: Option<usize>,
+    team_transfers: Option<bool>,
+    history_prefix: Option<String>,
+    language: Option<String>,
+    modern: Option<bool>,
+    script: Option<String>,
 }
 
 impl UserInterfaceOptions {
     fn new() -> Self {
         Self {
+            seed: None,
+            player_count: None,
+            names: None,
+            computer: None,
+            map_size: None,
+            star_probability: None,
+            company_count: None,
+            starting_cash: None,
+            candidate_move_count: None,
+            persistent_hands: None,
+            allow_pass: None,
+            reroll_fee: None,
             wizard_mode: None,
             max_turns: None,
+            loans: None,
+            loan_margin_percentage: None,
+            loan_call_percentage: None,
+            loan_interest_percentage: None,
+            random_events: None,
+            pirate_raid_probability: None,
+            pirate_raid_percentage: None,
+            trade_boom_probability: None,
+            trade_boom_percentage: None,
+            supernova_probability: None,
+            subsidy_probability: None,
+            subsidy_per_share: None,
+            price_curve: None,
+            limited_shares: None,
+            merge_bonus: None,
+            merge_rule: None,
+            safe_company_size: None,
+            dividend_schedule: None,
+            dividend_percentage_per_size: None,
+            suspend_dividends_after_merge: None,
+            max_rounds: None,
+            target_net_worth: None,
+            board_coverage_percentage: None,
+            end_when_all_safe: None,
+            team_size: None,
+            team_transfers: None,
+            history_prefix: None,
+            language: None,
+            modern: None,
+            script: None,
         }
     }
 }
@@ -48,15 +135,123 @@ impl UserInterface {
         ui::print_title();
 
         self.game.options.wizard_mode = self.wizard_mode();
+        self.game.options.seed = self.options.seed;
+        if let Some((width, height)) = self.options.map_size {
+            self.game.options.map_width = Some(width);
+            self.game.options.map_height = Some(height);
+        }
+        if let Some(star_probability) = self.options.star_probability {
+            self.game.options.star_probability = star_probability;
+        }
+        if let Some(company_count) = self.options.company_count {
+            self.game.options.company_count = company_count;
+        }
+        if let Some(starting_cash) = self.options.starting_cash {
+            self.game.options.starting_cash = starting_cash;
+        }
+        if let Some(count) = self.options.candidate_move_count {
+            self.game.options.candidate_move_count = Some(count);
+        }
+        if let Some(persistent_hands) = self.options.persistent_hands {
+            self.game.options.persistent_hands = persistent_hands;
+        }
+        if let Some(allow_pass) = self.options.allow_pass {
+            self.game.options.allow_pass = allow_pass;
+        }
+        if let Some(fee) = self.options.reroll_fee {
+            self.game.options.reroll_fee = Some(fee);
+        }
         if let Some(max_turns) = self.options.max_turns {
-            self.game.options.max_turns = max_turns;
+            self.game.options.max_turns = Some(max_turns);
+        }
+        if let Some(loans) = self.options.loans {
+            self.game.options.loans = loans;
+        }
+        if let Some(margin) = self.options.loan_margin_percentage {
+            self.game.options.loan_margin_percentage = margin;
+        }
+        if let Some(call) = self.options.loan_call_percentage {
+            self.game.options.loan_call_percentage = call;
+        }
+        if let Some(interest) = self.options.loan_interest_percentage {
+            self.game.options.loan_interest_percentage = interest;
+        }
+        if let Some(random_events) = self.options.random_events {
+            self.game.options.random_events = random_events;
+        }
+        if let Some(probability) = self.options.pirate_raid_probability {
+            self.game.options.pirate_raid_probability = probability;
+        }
+        if let Some(percentage) = self.options.pirate_raid_percentage {
+            self.game.options.pirate_raid_percentage = percentage;
+        }
+        if let Some(probability) = self.options.trade_boom_probability {
+            self.game.options.trade_boom_probability = probability;
+        }
+        if let Some(percentage) = self.options.trade_boom_percentage {
+            self.game.options.trade_boom_percentage = percentage;
+        }
+        if let Some(probability) = self.options.supernova_probability {
+            self.game.options.supernova_probability = probability;
+        }
+        if let Some(probability) = self.options.subsidy_probability {
+            self.game.options.subsidy_probability = probability;
+        }
+        if let Some(per_share) = self.options.subsidy_per_share {
+            self.game.options.subsidy_per_share = per_share;
+        }
+        if let Some(price_curve) = self.options.price_curve {
+            self.game.options.price_curve = price_curve;
         }
+        if let Some(limited_shares) = self.options.limited_shares {
+            self.game.options.limited_shares = limited_shares;
+        }
+        if let Some(merge_bonus) = self.options.merge_bonus {
+            self.game.options.merge_bonus = merge_bonus;
+        }
+        if let Some(merge_rule) = self.options.merge_rule {
+            self.game.options.merge_rule = merge_rule;
+        }
+        if let Some(safe_company_size) = self.options.safe_company_size {
+            self.game.options.safe_company_size = Some(safe_company_size);
+        }
+        if let Some(dividend_schedule) = self.options.dividend_schedule {
+            self.game.options.dividend_schedule = dividend_schedule;
+        }
+        if let Some(per_size) = self.options.dividend_percentage_per_size {
+            self.game.options.dividend_percentage_per_size = per_size;
+        }
+        if let Some(suspend) = self.options.suspend_dividends_after_merge {
+            self.game.options.suspend_dividends_after_merge = suspend;
+        }
+        if let Some(max_rounds) = self.options.max_rounds {
+            self.game.options.max_rounds = Some(max_rounds);
+        }
+        if let Some(target) = self.options.target_net_worth {
+            self.game.options.target_net_worth = Some(target);
+        }
+        if let Some(percentage) = self.options.board_coverage_percentage {
+            self.game.options.board_coverage_percentage = Some(percentage);
+        }
+        if let Some(all_safe) = self.options.end_when_all_safe {
+            self.game.options.end_when_all_safe = all_safe;
+        }
+        if let Some(team_size) = self.options.team_size {
+            self.game.options.team_size = Some(team_size);
+        }
+        if let Some(team_transfers) = self.options.team_transfers {
+            self.game.options.team_transfers = team_transfers;
+        }
+        self.game.options.record_history = self.options.history_prefix.is_some();
 
         loop {
             // Play again loop.
             self.get_player_count();
             self.game.reset();
-            ui::prompt_instructions();
+            // Games set up on the command line skip straight to play.
+            if self.options.player_count.is_none() {
+                ui::prompt_instructions(&self.game.options);
+            }
             self.get_player_names();
             self.go_first_message();
 
@@ -64,19 +259,32 @@ impl UserInterface {
                 // Main game loop
                 self.wizard_alert();
                 self.display_map();
-                self.game.begin_turn();
-                let candidates = self.game.get_moves();
+                let events = self.game.begin_turn();
+                self.handle_events(events);
 
-                // This can happen if there aren't enough moves remaining.
                 if self.game.game_is_over() {
                     break;
                 }
 
-                let move_point = self.get_move(&candidates);
-                let events = self.game.make_move(move_point);
+                // Players who go bankrupt at the start of their turn don't get to move.
+                if self.game.get_current_player().is_bankrupt() {
+                    let events = self.game.end_turn();
+                    self.handle_events(events);
+
+                    if self.game.game_is_over() {
+                        break;
+                    }
+
+                    continue;
+                }
+
+                let Some(events) = self.move_phase() else {
+                    break;
+                };
                 self.handle_events(events);
                 self.trade();
-                self.game.end_turn();
+                let events = self.game.end_turn();
+                self.handle_events(events);
 
                 if self.game.game_is_over() {
                     break;
@@ -84,6 +292,7 @@ impl UserInterface {
             }
 
             self.final_stats();
+            self.write_history();
             if !ui::play_again() {
                 break;
             }
@@ -94,12 +303,380 @@ impl UserInterface {
 /// Print usage.
 fn usage() {
     eprintln!("usage: starlanes [options]\n");
+    eprintln!("       -f f --config f      Read options from TOML file f, e.g. max-turns = 60");
+    eprintln!("       -S n --seed n        Seed the random numbers to replay a game");
+    eprintln!("       -P n --players n     Number of players (skips the setup questions)");
+    eprintln!("       -n l --names l       Comma-separated player names");
+    eprintln!("       -A l --computer l    Comma-separated seats played by the computer, e.g. 2,3");
+    eprintln!("       -x s --map-size s    Map width and height, e.g. 16x12");
+    eprintln!("       -b p --stars p       Percent of the map that is stars");
+    eprintln!("       -C n --companies n   Number of companies, 1-{MAX_COMPANIES}");
+    eprintln!("       -k n --cash n        Starting cash for each player");
+    eprintln!("       -o n --moves n       Number of moves offered each turn");
+    eprintln!("       -K   --hands         Keep unused moves in a hand for the next turn");
+    eprintln!("       -y   --pass          Allow passing instead of moving");
+    eprintln!("       -Y n --reroll n      Allow drawing new moves once a turn for $n");
     eprintln!("       -w   --wizard        Start in wizard mode");
+    eprintln!("       -M   --modern        Mixed case, numbered moves, and summaries");
     eprintln!("       -t n --max-turns n   Set maximum game turns");
+    eprintln!("       -R n --max-rounds n  End the game after n full rounds");
+    eprintln!("       -W n --target-worth n End the game when a player is worth n");
+    eprintln!("       -c p --coverage p    End the game when a company covers p% of the map");
+    eprintln!("       -a   --end-when-safe End the game when every company is safe");
+    eprintln!("       -T n --teams n       Play in teams of n players");
+    eprintln!("       -g   --team-transfers Allow giving cash to teammates");
+    eprintln!("       -l   --loans         Allow borrowing against stock");
+    eprintln!("            --loan-margin p Borrow up to p% of the value of your stock");
+    eprintln!("            --loan-call p   Sell stock when the loan is over p% of its value");
+    eprintln!("            --loan-interest p Charge p% interest on the loan every turn");
+    eprintln!("       -e   --events        Enable random galactic events");
+    eprintln!("            --raid-chance p p% chance each turn of a pirate raid");
+    eprintln!("            --raid-cut p    Pirate raids cut the share price by p%");
+    eprintln!("            --boom-chance p p% chance each turn of a trade boom");
+    eprintln!("            --boom-rise p   Trade booms raise the share price by p%");
+    eprintln!("            --supernova-chance p p% chance each turn of a supernova");
+    eprintln!("            --subsidy-chance p p% chance each turn of a subsidy");
+    eprintln!("            --subsidy n     Subsidies pay $n a share");
+    eprintln!("       -p c --price-curve c Trading moves prices: fixed, linear:N, or exp:PCT");
+    eprintln!("       -s   --limited-shares Limit shares to those issued by the bank");
+    eprintln!("       -m   --majority      Pay merge bonuses to the two largest shareholders");
+    eprintln!("       -r r --merge-rule r  Merge tie-break: classic, choice, price, or random");
+    eprintln!("       -z n --safe-size n   Companies of size n or larger can't be absorbed");
+    eprintln!("       -d   --round-dividends Pay all shareholders at the end of each round");
+    eprintln!("       -D p --dividend-per-size p  Add p percent dividend per company cell");
+    eprintln!("       -u   --suspend-dividends  No dividends from a company that just merged");
+    eprintln!("       -I f --script f      Read input from f, echoing it, to make a transcript");
+    eprintln!("       -H f --history f     Write price and holdings history to f-*.csv and f.json");
+    eprintln!(
+        "       -L l --language l    Game text: {} (default from locale)",
+        ui::catalog_names().join(", ")
+    );
     eprintln!();
 }
 
-/// Parse the command line.
+/// Parse a price curve spec, e.g. `linear:50`.
+fn parse_price_curve(spec: &str) -> Option<PriceCurve> {
+    let (kind, value) = spec.split_once(':').unwrap_or((spec, ""));
+
+    match kind {
+        "fixed" => Some(PriceCurve::Fixed),
+        "linear" => Some(PriceCurve::Linear(value.parse().ok()?)),
+        "exp" => Some(PriceCurve::Exponential(value.parse().ok()?)),
+        _ => None,
+    }
+}
+
+/// Parse a merge tie-break rule name.
+fn parse_merge_rule(name: &str) -> Option<MergeRule> {
+    match name {
+        "classic" => Some(MergeRule::Classic),
+        "choice" => Some(MergeRule::PlayerChoice),
+        "price" => Some(MergeRule::HighestPrice),
+        "random" => Some(MergeRule::Random),
+        _ => None,
+    }
+}
+
+/// Parse a map size, e.g. `16x12`. The columns are lettered, so the map can be at most 26
+/// wide.
+fn parse_map_size(spec: &str) -> Option<(usize, usize)> {
+    let (width, height) = spec.split_once(['x', 'X'])?;
+    let (width, height) = (width.parse().ok()?, height.parse().ok()?);
+
+    ((1..=26).contains(&width) && height > 0).then_some((width, height))
+}
+
+/// Parse a percentage from 0 to 100.
+fn parse_percentage(value: &str) -> Option<f32> {
+    let percentage: f32 = value.parse().ok()?;
+
+    (0.0..=100.0).contains(&percentage).then_some(percentage)
+}
+
+/// Parse a percentage that can be over 100, but not negative.
+fn parse_rate(value: &str) -> Option<f32> {
+    let rate: f32 = value.parse().ok()?;
+
+    (rate >= 0.0).then_some(rate)
+}
+
+/// Parse a comma-separated list, e.g. `2,3`.
+fn parse_list<T: std::str::FromStr>(list: &str) -> Option<Vec<T>> {
+    list.split(',').map(|v| v.trim().parse().ok()).collect()
+}
+
+/// Set the option for a command line flag, taking its value from `args` if it has one.
+fn set_option(
+    options: &mut UserInterfaceOptions,
+    flag: &str,
+    args: &mut impl Iterator<Item = String>,
+) -> Option<()> {
+    match flag {
+        "-S" | "--seed" => {
+            options.seed = Some(args.next()?.parse().ok()?);
+        }
+        "-P" | "--players" => {
+            options.player_count = Some(args.next()?.parse().ok()?);
+        }
+        "-n" | "--names" => {
+            options.names = Some(parse_list(&args.next()?)?);
+        }
+        "-A" | "--computer" => {
+            options.computer = Some(parse_list(&args.next()?)?);
+        }
+        "-x" | "--map-size" => {
+            options.map_size = Some(parse_map_size(&args.next()?)?);
+        }
+        "-b" | "--stars" => {
+            options.star_probability = Some(parse_percentage(&args.next()?)? / 100.0);
+        }
+        "-C" | "--companies" => {
+            options.company_count = Some(args.next()?.parse().ok()?);
+        }
+        "-k" | "--cash" => {
+            options.starting_cash = Some(args.next()?.parse().ok()?);
+        }
+        "-o" | "--moves" => {
+            options.candidate_move_count = Some(args.next()?.parse().ok()?);
+        }
+        "-K" | "--hands" => {
+            options.persistent_hands = Some(true);
+        }
+        "-y" | "--pass" => {
+            options.allow_pass = Some(true);
+        }
+        "-Y" | "--reroll" => {
+            options.reroll_fee = Some(args.next()?.parse().ok()?);
+        }
+        "--wizard" | "-w" => {
+            options.wizard_mode = Some(true);
+        }
+        "-t" | "--max-turns" => {
+            options.max_turns = Some(args.next()?.parse().ok()?);
+        }
+        "-R" | "--max-rounds" => {
+            options.max_rounds = Some(args.next()?.parse().ok()?);
+        }
+        "-W" | "--target-worth" => {
+            options.target_net_worth = Some(args.next()?.parse().ok()?);
+        }
+        "-c" | "--coverage" => {
+            options.board_coverage_percentage = Some(parse_percentage(&args.next()?)?);
+        }
+        "-a" | "--end-when-safe" => {
+            options.end_when_all_safe = Some(true);
+        }
+        "-T" | "--teams" => {
+            options.team_size = Some(args.next()?.parse().ok()?);
+        }
+        "-g" | "--team-transfers" => {
+            options.team_transfers = Some(true);
+        }
+        "--loans" | "-l" => {
+            options.loans = Some(true);
+        }
+        "--loan-margin" => {
+            options.loan_margin_percentage = Some(parse_percentage(&args.next()?)?);
+        }
+        "--loan-call" => {
+            options.loan_call_percentage = Some(parse_percentage(&args.next()?)?);
+        }
+        "--loan-interest" => {
+            options.loan_interest_percentage = Some(parse_rate(&args.next()?)?);
+        }
+        "--events" | "-e" => {
+            options.random_events = Some(true);
+        }
+        "--raid-chance" => {
+            options.pirate_raid_probability = Some(parse_percentage(&args.next()?)? / 100.0);
+        }
+        "--raid-cut" => {
+            options.pirate_raid_percentage = Some(parse_percentage(&args.next()?)?);
+        }
+        "--boom-chance" => {
+            options.trade_boom_probability = Some(parse_percentage(&args.next()?)? / 100.0);
+        }
+        "--boom-rise" => {
+            options.trade_boom_percentage = Some(parse_rate(&args.next()?)?);
+        }
+        "--supernova-chance" => {
+            options.supernova_probability = Some(parse_percentage(&args.next()?)? / 100.0);
+        }
+        "--subsidy-chance" => {
+            options.subsidy_probability = Some(parse_percentage(&args.next()?)? / 100.0);
+        }
+        "--subsidy" => {
+            let per_share: i64 = args.next()?.parse().ok()?;
+            options.subsidy_per_share = Some((per_share >= 0).then_some(per_share)?);
+        }
+        "-d" | "--round-dividends" => {
+            options.dividend_schedule = Some(DividendSchedule::EachRound);
+        }
+        "-D" | "--dividend-per-size" => {
+            options.dividend_percentage_per_size = Some(args.next()?.parse().ok()?);
+        }
+        "-u" | "--suspend-dividends" => {
+            options.suspend_dividends_after_merge = Some(true);
+        }
+        "-z" | "--safe-size" => {
+            options.safe_company_size = Some(args.next()?.parse().ok()?);
+        }
+        "-r" | "--merge-rule" => {
+            options.merge_rule = Some(parse_merge_rule(&args.next()?)?);
+        }
+        "--majority" | "-m" => {
+            options.merge_bonus = Some(MergeBonus::Majority);
+        }
+        "--limited-shares" | "-s" => {
+            options.limited_shares = Some(true);
+        }
+        "-M" | "--modern" => {
+            options.modern = Some(true);
+        }
+        "-L" | "--language" => {
+            options.language = Some(args.next()?);
+        }
+        "-I" | "--script" => {
+            options.script = Some(args.next()?);
+        }
+        "-H" | "--history" => {
+            options.history_prefix = Some(args.next()?);
+        }
+        "-p" | "--price-curve" => {
+            options.price_curve = Some(parse_price_curve(&args.next()?)?);
+        }
+        _ => {
+            return None;
+        }
+    }
+
+    Some(())
+}
+
+/// Read options from a TOML config file. The keys are the long flag names, e.g.
+/// `max-turns = 60`. Switches are `true` or `false`, and lists are arrays, e.g.
+/// `names = ["Alice", "Bob"]`.
+fn read_config(options: &mut UserInterfaceOptions, path: &str) -> Option<()> {
+    let table: toml::Table = match std::fs::read_to_string(path) {
+        Ok(text) => match text.parse() {
+            Ok(table) => table,
+            Err(err) => {
+                eprintln!("starlanes: {path}: {err}");
+                return None;
+            }
+        },
+        Err(err) => {
+            eprintln!("starlanes: {path}: {err}");
+            return None;
+        }
+    };
+
+    for (key, value) in table {
+        // Turn the value back into what would follow the flag on the command line.
+        let args = match value {
+            toml::Value::Boolean(false) => continue,
+            toml::Value::Boolean(true) => Some(Vec::new()),
+            toml::Value::Array(values) => values
+                .iter()
+                .map(config_string)
+                .collect::<Option<Vec<_>>>()
+                .map(|values| vec![values.join(",")]),
+            value => config_string(&value).map(|value| vec![value]),
+        };
+
+        // Files can't include other files, and asking for help makes no sense here.
+        let ok = key != "config"
+            && key != "help"
+            && args.is_some_and(|args| {
+                let mut args = args.into_iter();
+                set_option(options, &format!("--{key}"), &mut args).is_some()
+                    && args.next().is_none()
+            });
+
+        if !ok {
+            eprintln!("starlanes: {path}: bad option: {key}");
+            return None;
+        }
+    }
+
+    Some(())
+}
+
+/// Return a single TOML value as a command line argument.
+fn config_string(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::String(s) => Some(s.clone()),
+        toml::Value::Integer(n) => Some(n.to_string()),
+        toml::Value::Float(x) => Some(x.to_string()),
+        _ => None,
+    }
+}
+
+/// Check that the options make sense together, filling in the player count from the names if
+/// need be.
+fn check_options(options: &mut UserInterfaceOptions) -> Option<()> {
+    if let Some(names) = &options.names {
+        match options.player_count {
+            Some(count) if count != names.len() => return None,
+            _ => options.player_count = Some(names.len()),
+        }
+    }
+
+    // Teams need at least two players each, and some player count that splits into teams.
+    if let Some(team_size) = options.team_size
+        && (team_size < 2
+            || !(2..=MAX_PLAYERS)
+                .any(|count| StarLanes::team_player_count_is_valid(count, Some(team_size))))
+    {
+        return None;
+    }
+
+    if let Some(count) = options.player_count
+        && (!(2..=MAX_PLAYERS).contains(&count)
+            || !StarLanes::team_player_count_is_valid(count, options.team_size))
+    {
+        return None;
+    }
+
+    if let Some(seats) = &options.computer {
+        let max_seat = options.player_count.unwrap_or(MAX_PLAYERS);
+
+        if seats.iter().any(|seat| !(1..=max_seat).contains(seat)) {
+            return None;
+        }
+    }
+
+    if let Some(count) = options.company_count
+        && !(1..=MAX_COMPANIES).contains(&count)
+    {
+        return None;
+    }
+
+    if options.candidate_move_count == Some(0) {
+        return None;
+    }
+
+    if options.reroll_fee.is_some_and(|fee| fee < 0) {
+        return None;
+    }
+
+    // A loan called as soon as it's taken out would just be sold off again.
+    let defaults = StarLanesOptions::new();
+    let margin = options
+        .loan_margin_percentage
+        .unwrap_or(defaults.loan_margin_percentage);
+    let call = options
+        .loan_call_percentage
+        .unwrap_or(defaults.loan_call_percentage);
+    if call < margin {
+        return None;
+    }
+
+    Some(())
+}
+
+/// Parse the command line. Flags are applied in order, so flags after `--config` override the
+/// file.
 fn parse_command_line() -> Option<UserInterfaceOptions> {
     let mut options = UserInterfaceOptions::new();
 
@@ -111,19 +688,13 @@ fn parse_command_line() -> Option<UserInterfaceOptions> {
                 usage();
                 std::process::exit(0);
             }
-            "--wizard" | "-w" => {
-                options.wizard_mode = Some(true);
-            }
-            "-t" | "--max-turns" => {
-                let value = args.next();
-                options.max_turns = Some(value?.parse().unwrap());
-            }
-            _ => {
-                return None;
-            }
+            "-f" | "--config" => read_config(&mut options, &args.next()?)?,
+            flag => set_option(&mut options, flag, &mut args)?,
         }
     }
 
+    check_options(&mut options)?;
+
     Some(options)
 }
 
@@ -136,6 +707,35 @@ fn main() {
         std::process::exit(1);
     };
 
+    let modern = options.modern.unwrap_or(false);
+    ui::set_modern(modern);
+
+    // The modern UI speaks modern English unless told otherwise.
+    let language = match &options.language {
+        Some(language) => Some(language.as_str()),
+        None if modern => Some("modern"),
+        None => None,
+    };
+
+    if !ui::select_catalog(language) {
+        usage();
+        std::process::exit(1);
+    }
+
+    if let Some(script) = &options.script {
+        match std::fs::File::open(script) {
+            Ok(file) => ui::set_console(ui::Console::new(
+                Box::new(std::io::BufReader::new(file)),
+                Box::new(std::io::stdout()),
+                true,
+            )),
+            Err(err) => {
+                eprintln!("starlanes: {script}: {err}");
+                std::process::exit(1);
+            }
+        }
+    }
+
     let mut user_interface = UserInterface::new(options);
 
     user_interface.game_loop();
@@ -1,10 +1,126 @@
 //! I/O functions that tend to ease porting from BASIC.
-use std::io;
-use std::io::Write;
+//!
+//! All input and output goes through the [`Console`], which is the terminal unless a script has
+//! been set up with [`set_console`]. Use [`out!`] and [`outln!`] instead of `print!` and
+//! `println!`.
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, Mutex};
 
-/// Prints a formfeed.
+/// Where the UI reads its input from and writes its output to.
+pub struct Console {
+    /// Input source, read a line at a time.
+    input: Box<dyn BufRead + Send>,
+    /// Output sink.
+    output: Box<dyn Write + Send>,
+    /// True if input should be written to the output as it's read, so a scripted game makes a
+    /// complete transcript.
+    echo: bool,
+}
+
+impl Console {
+    /// Construct a new console reading from `input` and writing to `output`.
+    pub fn new(input: Box<dyn BufRead + Send>, output: Box<dyn Write + Send>, echo: bool) -> Self {
+        Self {
+            input,
+            output,
+            echo,
+        }
+    }
+
+    /// Construct a console for the terminal.
+    pub fn terminal() -> Self {
+        Self::new(
+            Box::new(io::BufReader::new(io::stdin())),
+            Box::new(io::stdout()),
+            false,
+        )
+    }
+}
+
+/// The console in use.
+static CONSOLE: LazyLock<Mutex<Console>> = LazyLock::new(|| Mutex::new(Console::terminal()));
+
+/// Use a different console for all input and output.
+pub fn set_console(console: Console) {
+    *CONSOLE.lock().unwrap() = console;
+}
+
+/// Write text to the console.
+pub fn write(s: &str) {
+    _ = CONSOLE.lock().unwrap().output.write_all(s.as_bytes());
+}
+
+/// Like `print!`, but to the console.
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::ui::write(&format!($($arg)*))
+    };
+}
+
+/// Like `println!`, but to the console.
+macro_rules! outln {
+    () => {
+        $crate::ui::write("\n")
+    };
+    ($($arg:tt)*) => {
+        $crate::ui::write(&format!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+pub(crate) use {out, outln};
+
+/// Prompt for and read a line from the console, without the line ending.
+///
+/// If the input runs out, there's no one left to play, so this exits.
+fn read_line() -> String {
+    let mut console = CONSOLE.lock().unwrap();
+
+    _ = console.output.write_all(b"? ");
+    _ = console.output.flush();
+
+    let mut input = String::new();
+
+    match console.input.read_line(&mut input) {
+        Ok(n) if n > 0 => (),
+        _ => {
+            _ = console.output.write_all(b"\n");
+            _ = console.output.flush();
+            std::process::exit(0);
+        }
+    }
+
+    let input = input.trim_end_matches(['\r', '\n']).to_string();
+
+    if console.echo {
+        _ = writeln!(console.output, "{input}");
+    }
+
+    input
+}
+
+/// True if the modern UI is in use instead of the classic one.
+static MODERN: AtomicBool = AtomicBool::new(false);
+
+/// Turn the modern UI on or off.
+///
+/// The modern UI drops the BASIC-isms: form feeds, bells, and announcement spacing.
+pub fn set_modern(modern: bool) {
+    MODERN.store(modern, Ordering::Relaxed);
+}
+
+/// Return true if the modern UI is in use.
+pub fn is_modern() -> bool {
+    MODERN.load(Ordering::Relaxed)
+}
+
+/// Prints a formfeed. The modern UI just leaves a blank line.
 pub fn formfeed() {
-    print!("{}", 12 as char);
+    if is_modern() {
+        outln!();
+    } else {
+        out!("{}", 12 as char);
+    }
 }
 
 /// Moves the cursor to the nth column to the right.
@@ -18,22 +134,47 @@ pub fn tab(n: usize) -> String {
     format!("{:>width$}", "", width = n - 1)
 }
 
-/// Read a line of text from stdin.
+/// Read a line of text from the console.
 ///
 /// This simulates the BASIC `INPUT` statement to a degree, the main difference being that it
 /// always returns a string. It's up to the caller to convert to other types as needed.
 pub fn input() -> String {
-    print!("? ");
-    _ = io::stdout().flush();
-
-    let mut input = String::new();
-    _ = io::stdin().read_line(&mut input);
+    let input = read_line();
 
     // Original game only allowed uppercase input, but we'll take this liberty to keep the user
     // from going insane.
     input.to_uppercase().trim().to_string()
 }
 
+/// Read a line of free text, such as a name, from the console.
+///
+/// Unlike [`input`], this only uppercases if the message catalog does.
+pub fn input_text() -> String {
+    let input = read_line();
+
+    if crate::ui::text().uppercase_input {
+        input.to_uppercase().trim().to_string()
+    } else {
+        input.trim().to_string()
+    }
+}
+
+/// Parse an amount of cash or stock typed at a prompt.
+///
+/// Blank input is zero. The classic UI treats anything else that isn't a number as zero, too, but
+/// the modern UI returns `None` so the caller can ask again.
+pub fn parse_amount(input: &str) -> Option<i64> {
+    if input.is_empty() {
+        return Some(0);
+    }
+
+    match input.parse::<i64>() {
+        Ok(n) => Some(n),
+        Err(_) if is_modern() => None,
+        Err(_) => Some(0),
+    }
+}
+
 /// Format a number with a leading space if positive and a minus if negative.
 pub fn format_num_signed(n: i64) -> String {
     if n >= 0 {
@@ -0,0 +1,256 @@
+//! Classic uppercase English, as in the original game.
+use super::Catalog;
+
+pub(super) const CATALOG: Catalog = Catalog {
+    name: "classic",
+    locales: &["en"],
+    uppercase_input: true,
+
+    title: "* S * T * A * R ** L * A * N * E * S *",
+    special_announcement: "SPECIAL ANNOUNCEMENT!!",
+    wizard_mode: "*** WIZARD MODE ***",
+    company_names: [
+        "ALTAIR STARWAYS",
+        "BETELGEUSE, LTD.",
+        "CAPELLA FREIGHT CO.",
+        "DENEBOLA SHIPPERS",
+        "ERIDANI EXPEDITERS",
+    ],
+    company_formed: "A NEW SHIPPING COMPANY HAS BEEN FORMED!\nIT'S NAME IS {0}",
+
+    player_count_prompt: "HOW MANY PLAYERS ({0}-{1})",
+    player_count_invalid: "PLEASE ENTER A NUMBER FROM {0} TO {1}",
+    player_count_list_prompt: "HOW MANY PLAYERS ({0})",
+    player_count_list_invalid: "PLEASE ENTER ONE OF {0}",
+    player_name_prompt: "PLAYER {0} WHAT IS YOUR NAME",
+    player_team_name_prompt: "PLAYER {0} (TEAM {1}) WHAT IS YOUR NAME",
+    computer_name: "CPU {0}",
+    deciding_first: "\nNOW I WILL DECIDED WHO GOES FIRST...\n", // DECIDED sic
+    first_player: "{0} IS THE FIRST PLAYER TO MOVE.\n",
+
+    map_title: "MAP OF THE GALAXY",
+
+    legal_moves: ", HERE ARE YOUR LEGAL MOVES FOR THIS TURN:",
+    move_prompt: "WHAT IS YOUR MOVE",
+    move_menu_prompt: "WHAT IS YOUR MOVE (1-{0}, OR ROW AND COLUMN)",
+    not_in_list: "THAT SPACE WAS NOT INCLUDED IN THE LIST...",
+    survivor_tied: "THE FOLLOWING COMPANIES ARE TIED FOR LARGEST:",
+    survivor_prompt: "WHICH COMPANY SHOULD SURVIVE THE MERGER",
+    hint_outpost: " {0}  NEW OUTPOST",
+    hint_growth: " {0}  {1} GROWS, STOCK TO $ {2}",
+    hint_new_company: " {0}  FORMS {1}, STOCK AT $ {2}",
+    hint_merge: " {0}  {1} MERGES INTO {2}, STOCK TO $ {3}, YOUR BONUS $ {4}",
+    hint_split: " AFTER SPLITTING",
+    computer_move: "{0} MOVES TO {1}{2}",
+    already_rerolled: "YOU HAVE ALREADY DRAWN NEW MOVES THIS TURN",
+    survivor_invalid: "THAT COMPANY IS NOT ONE OF THE CHOICES...",
+
+    loan_status: "YOUR CURRENT LOAN= $ {0}   CREDIT LIMIT= $ {1}",
+    borrow_prompt: "BORROW HOW MUCH (NEGATIVE TO REPAY)",
+    over_credit_limit: "YOU CAN ONLY BORROW $ {0} - TRY AGAIN",
+    too_little_cash: "YOU ONLY HAVE $ {0} - TRY AGAIN",
+    over_repayment: "YOU ONLY OWE $ {0} - TRY AGAIN",
+    current_cash: "YOUR CURRENT CASH= $ {0}",
+    give_prompt: "GIVE HOW MUCH CASH TO {0}",
+    buy_prompt: "BUY HOW MANY SHARES OF {0} AT $ {1}",
+    shares_available: "{0} SHARES AVAILABLE",
+    you_now_own: "YOU NOW OWN {0} ",
+    quote_cost: "THAT WILL COST $ {0}",
+    quote_pay: "THAT WILL PAY $ {0}",
+    quote_price: " AND MOVE THE PRICE TO $ {0}. OK",
+    too_few_shares: "ONLY {0} SHARES ARE AVAILABLE - TRY AGAIN",
+    too_little_stock: "YOU ONLY HAVE {0} SHARES - TRY AGAIN",
+    computer_trade: "{0} TRADES {1} SHARES OF {2}",
+    not_a_number: "PLEASE ENTER A NUMBER",
+
+    col_player: "PLAYER",
+    col_stock: "STOCK",
+    col_size: "SIZE",
+    col_price_per_share: "PRICE PER SHARE",
+    col_your_holdings: "YOUR HOLDINGS",
+    col_available: "AVAILABLE",
+    col_teammate_holdings: "TEAMMATE HOLDINGS",
+    col_cash: "CASH",
+    col_dividend: "DIVIDEND",
+    col_old_stock: "OLD STOCK",
+    col_new_stock: "NEW STOCK",
+    col_total_holdings: "TOTAL HOLDINGS",
+    col_bonus_paid: "BONUS PAID",
+    col_shares_sold: "SHARES SOLD",
+    col_proceeds: "PROCEEDS",
+    col_turn: "TURN",
+    col_transaction: "TRANSACTION",
+    col_shares: "SHARES",
+    col_loan: "LOAN",
+    col_cash_value_of_stock: "CASH VALUE OF STOCK",
+    col_cash_on_hand: "CASH ON HAND",
+    col_net_worth: "NET WORTH",
+    col_team: "TEAM",
+    col_players: "PLAYERS",
+
+    loan_line: "\nLOAN= $ {0}   CREDIT LIMIT= $ {1}",
+    summary_title: "POSITION OF {0}:",
+    summary_worth: "CASH= $ {0}   NET WORTH= $ {1}",
+
+    split: "THE STOCK OF {0} HAS SPLIT {1} FOR 1!",
+    merged: "{0} HAS JUST BEEN MERGED INTO {1}!\nPLEASE NOTE THE FOLLOWING TRANSACTIONS.\n",
+    loan_interest: "{0} PAYS $ {1} INTEREST ON LOANS",
+    loan_called: "THE BANK HAS CALLED THE LOAN OF {0}!\nTHE FOLLOWING STOCK HAS BEEN SOLD.\n",
+    bankrupt: "{0} HAS GONE BANKRUPT AND IS OUT OF THE GAME!",
+    pirate_raid: "PIRATES HAVE RAIDED THE LANES OF {0}!\n\
+                  ITS STOCK HAS FALLEN FROM $ {1} TO $ {2}.",
+    trade_boom: "{0} IS ENJOYING A TRADE BOOM!\nITS STOCK HAS RISEN FROM $ {1} TO $ {2}.",
+    supernova: "THE STAR AT {0} {1} HAS GONE SUPERNOVA!",
+    supernova_company: "THE STOCK OF {0} HAS LOST VALUE.",
+    subsidy: "THE GALACTIC COUNCIL HAS SUBSIDIZED {0}!\n\
+              ALL SHAREHOLDERS RECEIVE $ {1} PER SHARE.",
+    company_safe: "{0} IS NOW TOO LARGE TO BE TAKEN OVER!",
+    new_president: "{0} IS THE NEW PRESIDENT OF {1}!",
+    passed: "{0} PASSES",
+    rerolled: "{0} PAYS $ {1} FOR NEW MOVES",
+
+    ledger_purchase: "PURCHASE",
+    ledger_sale: "SALE",
+    ledger_dividend: "DIVIDEND",
+    ledger_founder_shares: "FOUNDER SHARES",
+    ledger_merge_bonus: "MERGE BONUS",
+    ledger_merge_conversion: "MERGE OF {0}",
+    ledger_split: "STOCK SPLIT",
+    ledger_subsidy: "SUBSIDY",
+    ledger_loan: "LOAN",
+    ledger_loan_interest: "LOAN INTEREST",
+    ledger_forced_sale: "LOAN CALLED",
+    ledger_transfer: "TRANSFER {0}",
+    ledger_reroll_fee: "NEW MOVES",
+    ledger_bankruptcy: "BANKRUPTCY",
+    no_transactions: "NO TRANSACTIONS YET",
+
+    game_over_target_worth: "{0} HAS REACHED THE TARGET NET WORTH!",
+    game_over_board_covered: "{0} HAS TAKEN OVER THE GALAXY!",
+    game_over_all_safe: "EVERY COMPANY IS NOW TOO LARGE TO BE TAKEN OVER!",
+    game_over_max_rounds: "THE LAST ROUND HAS BEEN PLAYED!",
+    game_over_bankruptcy: "THERE IS NO ONE LEFT TO PLAY!",
+    game_over_no_moves: "THERE ARE NOT ENOUGH MOVES LEFT!",
+    final_standings: "THE GAME IS OVER - HERE ARE THE FINAL STANDINGS\n",
+    went_bankrupt: "{0} WENT BANKRUPT",
+    owes: "{0} OWES $ {1}",
+    play_again: "ANOTHER GAME",
+
+    instructions_prompt: "DOES ANY PLAYER NEED INSTRUCTIONS",
+    number_words: [
+        "ZERO", "ONE", "TWO", "THREE", "FOUR", "FIVE", "SIX", "SEVEN", "EIGHT", "NINE", "TEN",
+    ],
+    rules_intro: "\n   STAR LANES IS A GAME OF INTERSTELLAR TRADING.\n\
+        THE OBJECT OF THE GAME IS TO AMASS THE GREATEST AMOUNT\n\
+        OF MONEY. THIS IS ACCOMPLISHED BY ESTABLISHING VAST,\n\
+        INTERSTELLAR SHIPPING LANES, AND PURCHASING STOCK IN\n\
+        THE COMPANIES THAT CONTROL THOSE TRADE ROUTES. DURING\n\
+        THE COURSE OF THE GAME, STOCK APPRECIATES IN VALUE AS\n\
+        THE SHIPPING COMPANIES BECOME LARGER. ALSO, SMALLER\n\
+        COMPANIES CAN BE MERGED INTO LARGER ONES, AND STOCK\n\
+        IN THE SMALLER FIRM IS CONVERTED INTO STOCK IN THE \n\
+        LARGER ONE AS DESCRIBED BELOW.\n",
+    rules_moves: "   EACH TURN, THE COMPUTER WILL PRESENT THE PLAYER WITH\n\
+        {0} PROSPECTIVE SPACES TO OCCUPY ON A {1}X{2} MATRIX\n\
+        (ROWS 1-{1}, COLUMNS A-{3}). THE PLAYER, AFTER EXAMINING\n\
+        THE MAP OF THE GALAXY TO DECIDE WHICH SPACE HE WISHES\n\
+        TO OCCUPY, RESPONDS WITH THE ROW AND COLUMN OF THAT\n\
+        SPACE, I.E., 7E, 8A, ETC. THERE ARE FOUR POSSIBLE\n\
+        MOVES A PLAYER CAN MAKE.\n",
+    rules_outpost: "   1. HE CAN ESTABLISH AN UNATTACHED OUTPOST- IF HE\n\
+        SELECTS A SPACE THAT IS NOT ADJACENT TO A STAR, ANOTHER\n\
+        UNATTACHED OUTPOST, OR AN EXISTING SHIPPING LANE, THIS\n\
+        SPACE WILL BE DESIGNATED WITH A '+'. HE WILL THEN PROCEED\n\
+        WITH STOCK TRANSACTIONS, AS LISTED BELOW.\n",
+    rules_add_lane: "   2. HE CAN ADD TO AN EXISTING LANE- IF HE SELECTS A SPACE\n\
+        WHICH IS ADJACENT TO ONE - AND ONLY ONE EXISTING SHIPPING\n\
+        LANE, THE SPACE HE SELECTS WILL BE ADDED TO THAT SHIPPING\n\
+        LANE, AND WILL BE DESIGNATED WITH THE FIRST LETTER OF \n\
+        THE COMPANY THAT OWNS THAT LANE. IF THERE ARE ANY STARS\n\
+        OR UNATTACHED OUTPOSTS ALSO ADJACENT TO THE SELECTED SPACE,\n\
+        THEY, TOO, WILL BE INCORPORATED INTO THE EXISTING LANE.\n\
+        EACH NEW SQUARE ADJACENT TO A STAR ADDS $500 PER SHARE, AND\n\
+        EACH NEW OUTPOST ADDS $100 PER SHARE TO THE MARKET VALUE\n\
+        OF THE STOCK OF THAT SHIPPING COMPANY.\n",
+    rules_new_lane: "   3. HE MAY ESTABLISH A NEW SHIPPING LANE- IF THERE\n\
+        ARE FIVE OR LESS EXISTING SHIPPING LANES ESTABLISHED,\n\
+        THE PLAYER MAY, GIVEN THE PROPER SPACE TO PLAY, ESTABLISH\n\
+        A NEW SHIPPING LANE. HE MAY DO THIS BY OCCUPYING A SPACE\n\
+        ADJACENT TO A STAR OR ANOTHER UNATTACHED OUTPOST, BUT \n\
+        NOT ADJACENT TO AN EXISTING SHIPPING LANE. IF HE \n\
+        ESTABLISHES A NEW SHIPPING LANE, HE IS AUTOMATICALLY\n\
+        ISSUED 5 SHARES IN THE NEW COMPANY AS A REWARD. HE\n\
+        MAY THEN PROCEED TO BUY STOCK IN THAT COMPANY, OR ANY\n\
+        OTHER ACTIVE COMPANY, AS DESCRIBED BELOW. THE MARKET \n\
+        VALUE OF THE NEW STOCK IS ESTABLISHED BY THE NUMBER OF\n\
+        STARS AND OCCUPIED SPACES AS DESCRIBED IN #2 ABOVE.\n",
+    rules_merge: "   4. HE MAY MERGE TWO EXISTING COMPANIES- IF PLAYER\n\
+        SELECTS A SPACE ADJACENT TO TWO EXISTING SHIPPING\n\
+        LANES, A MERGER OCCURS. THE LARGER COMPANY TAKES OVER\n\
+        THE SMALLER COMPANY, THE STOCK OF THE LARGER COMPANY IS\n\
+        INCREASED IN VALUE ACCORDING TO THE NUMBER OF SPACES AND\n\
+        STARS ADDED TO ITS LANE, EACH PLAYER'S STOCK IN THE\n\
+        SMALLER COMPANY IS EXCHANGED FOR SHARES IN THE LARGER\n\
+        ON A RATIO OF 2 SHARES OF THE SMALLER = 1 SHARE OF THE",
+    rules_merge_proportional: "LARGER. ALSO, EACH PLAYER IS PAID A CASH BONUS PROPORTIONAL\n\
+        TO THE PERCENTAGE OF OUTSTANDING STOCK HE HELD IN THE",
+    rules_merge_majority: "LARGER. ALSO, THE PRESIDENT OF THE SMALLER COMPANY (THE\n\
+        PLAYER HOLDING THE MOST STOCK) IS PAID A BONUS OF {0} TIMES\n\
+        ITS SHARE PRICE, AND THE SECOND-LARGEST SHAREHOLDER IS\n\
+        PAID {1} TIMES ITS SHARE PRICE. TIED PLAYERS SPLIT THE\n\
+        BONUSES. NO OTHER PLAYERS RECEIVE A BONUS FROM THE",
+    rules_merge_end: "SMALLER COMPANY. NOTE: AFTER A COMPANY BECOMES DEFUNCT\n\
+        THROUGH THIS MERGER PROCESS, IT CAN REAPPEAR ELSEWHERE\n\
+        ON THE BOARD IF A NEW COMPANY IS ESTABLISHED (SEE #3 ABOVE)\n",
+    rules_merge_choice: "   IF THE COMPANIES BEING MERGED ARE THE SAME SIZE, THE\n\
+        PLAYER CHOOSES WHICH ONE TAKES OVER THE OTHERS.\n",
+    rules_merge_price: "   IF THE COMPANIES BEING MERGED ARE THE SAME SIZE, THE\n\
+        ONE WITH THE HIGHEST STOCK PRICE TAKES OVER THE OTHERS.\n",
+    rules_merge_random: "   IF THE COMPANIES BEING MERGED ARE THE SAME SIZE, THE\n\
+        ONE THAT TAKES OVER THE OTHERS IS CHOSEN AT RANDOM.\n",
+    rules_dividend_per_size: "   LARGER COMPANIES PAY BETTER DIVIDENDS: EACH SPACE IN A\n\
+        LANE ADDS {0}% TO THE DIVIDEND RATE OF ITS STOCK.\n",
+    rules_suspend_dividends: "   A COMPANY THAT HAS JUST TAKEN OVER ANOTHER DOES NOT\n\
+        PAY ITS NEXT DIVIDEND.\n",
+    rules_safe_size: "   A COMPANY WITH {0} OR MORE SPACES IS SAFE AND\n\
+        CAN NEVER BE TAKEN OVER. SPACES THAT WOULD MERGE TWO SAFE\n\
+        COMPANIES WILL NOT BE OFFERED AS MOVES.\n",
+    rules_hands: "   THE SPACES OFFERED TO A PLAYER ARE HIS HAND, AND HE\n\
+        KEEPS THE ONES HE DOES NOT USE FOR HIS NEXT TURN. HE IS\n\
+        ONLY GIVEN NEW SPACES TO REPLACE THE ONE HE OCCUPIED AND\n\
+        ANY HE CAN NO LONGER USE.\n",
+    rules_pass: "   INSTEAD OF MOVING, A PLAYER MAY TYPE 'PASS' AND GO\n\
+        STRAIGHT ON TO HIS STOCK TRANSACTIONS.\n",
+    rules_reroll: "   ONCE A TURN, A PLAYER MAY TYPE 'REROLL' AND PAY $ {0}\n\
+        TO BE GIVEN NEW SPACES TO CHOOSE FROM.\n",
+    rules_dividends_every_move: "   NEXT THE COMPUTER ADDS STOCK DIVIDENDS TO THE\n\
+        PLAYER'S CASH ON HAND ({0}% OF THE MARKET VALUE OF THE \n\
+        STOCK IN HIS POSSESSION), AND OFFERS HIM THE OPPORTUNITY TO",
+    rules_dividends_each_round: "   AT THE END OF EACH ROUND, THE COMPUTER ADDS STOCK\n\
+        DIVIDENDS TO EVERY PLAYER'S CASH ON HAND ({0}% OF THE\n\
+        MARKET VALUE OF THEIR STOCK). AFTER EACH MOVE, THE\n\
+        COMPUTER OFFERS THE PLAYER THE OPPORTUNITY TO",
+    rules_purchase: "PURCHASE STOCK IN ANY OF THE ACTIVE COMPANIES ON THE\n\
+        BOARD. STOCK MAY NOT BE SOLD, BUT THE MARKET VALUES OF\n\
+        EACH PLAYER'S STOCK IS TAKEN INTO ACCOUNT AT THE END\n\
+        OF THE GAME TO DETERMINE THE WINNER. IF THE MARKET VALUE\n\
+        OF A GIVEN STOCK EXCEEDS $3000 AT ANY TIME DURING THE \n\
+        GAME, THAT STOCK SPLITS TWO FOR ONE. THE PRICE IS CUT\n\
+        IN HALF, AND THE NUMBER OF SHARES OWNED BY EACH PLAYER\n\
+        IS DOUBLED.\n",
+    rules_commands: "NOTE: THE PLAYER MAY LOOK AT HIS PORTFOLIO AT ANY TIME\n\
+        DURING THE COURSE OF HIS TURN BY RESPONDING WITH 'STOCK'\n\
+        TO AN INPUT STATEMENT. LIKEWISE, HE CAN REVIEW THE MAP\n\
+        OF THE GALAXY BY TYPING 'MAP' TO AN INPUT STATEMENT.\n\n\
+        TYPING 'LEDGER' LISTS EVERY PURCHASE, SALE, DIVIDEND,\n\
+        BONUS, AND SPLIT IN HIS ACCOUNT SO FAR. TYPING 'HINT'\n\
+        WHEN CHOOSING A MOVE SHOWS WHAT EACH LEGAL MOVE WOULD DO.\n",
+    rules_max_rounds: "GAME ENDS AFTER {0} ROUNDS. PLAYER WITH THE GREATEST",
+    rules_max_turns: "GAME ENDS AFTER {0} MOVES. PLAYER WITH THE GREATEST",
+    rules_winner: "NET WORTH AT THAT POINT IS THE WINNER.\n",
+    rules_target: "THE GAME ALSO ENDS AS SOON AS A PLAYER'S NET WORTH\n\
+        REACHES $ {0}.\n",
+    rules_coverage: "THE GAME ALSO ENDS AS SOON AS A COMPANY COVERS {0}%\n\
+        OF THE GALAXY.\n",
+    rules_all_safe: "THE GAME ALSO ENDS AS SOON AS EVERY COMPANY IS SAFE.\n",
+};
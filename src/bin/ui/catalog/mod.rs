@@ -0,0 +1,287 @@
+//! Message catalogs.
+//!
+//! Every piece of text the UI shows comes from a [`Catalog`], one per language. Messages with
+//! values in them use numbered placeholders, `{0}`, `{1}`, etc., so a translation can put them in
+//! whatever order it needs. See [`fill`].
+//!
+//! The commands typed at prompts (`MAP`, `STOCK`, `LEDGER`, `HINT`) and yes/no answers are
+//! matched on their first letter and aren't translated.
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+mod classic;
+mod modern;
+
+/// All the catalogs, the default first.
+const CATALOGS: [&Catalog; 2] = [&classic::CATALOG, &modern::CATALOG];
+
+/// The catalog in use.
+static CURRENT: OnceLock<&'static Catalog> = OnceLock::new();
+
+/// A complete set of UI text for one language.
+pub struct Catalog {
+    /// Name used to select this catalog on the command line.
+    pub name: &'static str,
+    /// Locale language codes, e.g. `en`, that select this catalog from the environment.
+    pub locales: &'static [&'static str],
+    /// True if typed-in text like player names should be uppercased, as in the original game.
+    pub uppercase_input: bool,
+
+    // General.
+    pub title: &'static str,
+    pub special_announcement: &'static str,
+    pub wizard_mode: &'static str,
+    pub company_names: [&'static str; 5],
+    pub company_formed: &'static str,
+
+    // Player setup.
+    pub player_count_prompt: &'static str,
+    pub player_count_invalid: &'static str,
+    pub player_count_list_prompt: &'static str,
+    pub player_count_list_invalid: &'static str,
+    pub player_name_prompt: &'static str,
+    pub player_team_name_prompt: &'static str,
+    pub computer_name: &'static str,
+    pub deciding_first: &'static str,
+    pub first_player: &'static str,
+
+    // Map.
+    pub map_title: &'static str,
+
+    // Moves and merges.
+    pub legal_moves: &'static str,
+    pub move_prompt: &'static str,
+    pub move_menu_prompt: &'static str,
+    pub not_in_list: &'static str,
+    pub survivor_tied: &'static str,
+    pub survivor_prompt: &'static str,
+    pub survivor_invalid: &'static str,
+    pub computer_move: &'static str,
+    pub already_rerolled: &'static str,
+    pub hint_outpost: &'static str,
+    pub hint_growth: &'static str,
+    pub hint_new_company: &'static str,
+    pub hint_merge: &'static str,
+    pub hint_split: &'static str,
+
+    // Loans, transfers, and trading.
+    pub loan_status: &'static str,
+    pub borrow_prompt: &'static str,
+    pub over_credit_limit: &'static str,
+    pub too_little_cash: &'static str,
+    pub over_repayment: &'static str,
+    pub current_cash: &'static str,
+    pub give_prompt: &'static str,
+    pub buy_prompt: &'static str,
+    pub shares_available: &'static str,
+    pub you_now_own: &'static str,
+    pub quote_cost: &'static str,
+    pub quote_pay: &'static str,
+    pub quote_price: &'static str,
+    pub too_few_shares: &'static str,
+    pub too_little_stock: &'static str,
+    pub not_a_number: &'static str,
+    pub computer_trade: &'static str,
+
+    // Table column headings.
+    pub col_player: &'static str,
+    pub col_stock: &'static str,
+    pub col_size: &'static str,
+    pub col_price_per_share: &'static str,
+    pub col_your_holdings: &'static str,
+    pub col_available: &'static str,
+    pub col_teammate_holdings: &'static str,
+    pub col_cash: &'static str,
+    pub col_dividend: &'static str,
+    pub col_old_stock: &'static str,
+    pub col_new_stock: &'static str,
+    pub col_total_holdings: &'static str,
+    pub col_bonus_paid: &'static str,
+    pub col_shares_sold: &'static str,
+    pub col_proceeds: &'static str,
+    pub col_turn: &'static str,
+    pub col_transaction: &'static str,
+    pub col_shares: &'static str,
+    pub col_loan: &'static str,
+    pub col_cash_value_of_stock: &'static str,
+    pub col_cash_on_hand: &'static str,
+    pub col_net_worth: &'static str,
+    pub col_team: &'static str,
+    pub col_players: &'static str,
+
+    // Holdings and summaries.
+    pub loan_line: &'static str,
+    pub summary_title: &'static str,
+    pub summary_worth: &'static str,
+
+    // Events.
+    pub split: &'static str,
+    pub merged: &'static str,
+    pub loan_interest: &'static str,
+    pub loan_called: &'static str,
+    pub bankrupt: &'static str,
+    pub pirate_raid: &'static str,
+    pub trade_boom: &'static str,
+    pub supernova: &'static str,
+    pub supernova_company: &'static str,
+    pub subsidy: &'static str,
+    pub company_safe: &'static str,
+    pub new_president: &'static str,
+    pub passed: &'static str,
+    pub rerolled: &'static str,
+
+    // Ledger.
+    pub ledger_purchase: &'static str,
+    pub ledger_sale: &'static str,
+    pub ledger_dividend: &'static str,
+    pub ledger_founder_shares: &'static str,
+    pub ledger_merge_bonus: &'static str,
+    pub ledger_merge_conversion: &'static str,
+    pub ledger_split: &'static str,
+    pub ledger_subsidy: &'static str,
+    pub ledger_loan: &'static str,
+    pub ledger_loan_interest: &'static str,
+    pub ledger_forced_sale: &'static str,
+    pub ledger_transfer: &'static str,
+    pub ledger_reroll_fee: &'static str,
+    pub ledger_bankruptcy: &'static str,
+    pub no_transactions: &'static str,
+
+    // End of game.
+    pub game_over_target_worth: &'static str,
+    pub game_over_board_covered: &'static str,
+    pub game_over_all_safe: &'static str,
+    pub game_over_max_rounds: &'static str,
+    pub game_over_bankruptcy: &'static str,
+    pub game_over_no_moves: &'static str,
+    pub final_standings: &'static str,
+    pub went_bankrupt: &'static str,
+    pub owes: &'static str,
+    pub play_again: &'static str,
+
+    // Instructions.
+    pub instructions_prompt: &'static str,
+    pub number_words: [&'static str; 11],
+    pub rules_intro: &'static str,
+    pub rules_moves: &'static str,
+    pub rules_outpost: &'static str,
+    pub rules_add_lane: &'static str,
+    pub rules_new_lane: &'static str,
+    pub rules_merge: &'static str,
+    pub rules_merge_proportional: &'static str,
+    pub rules_merge_majority: &'static str,
+    pub rules_merge_end: &'static str,
+    pub rules_merge_choice: &'static str,
+    pub rules_merge_price: &'static str,
+    pub rules_merge_random: &'static str,
+    pub rules_dividend_per_size: &'static str,
+    pub rules_suspend_dividends: &'static str,
+    pub rules_safe_size: &'static str,
+    pub rules_hands: &'static str,
+    pub rules_pass: &'static str,
+    pub rules_reroll: &'static str,
+    pub rules_dividends_every_move: &'static str,
+    pub rules_dividends_each_round: &'static str,
+    pub rules_purchase: &'static str,
+    pub rules_commands: &'static str,
+    pub rules_max_rounds: &'static str,
+    pub rules_max_turns: &'static str,
+    pub rules_winner: &'static str,
+    pub rules_target: &'static str,
+    pub rules_coverage: &'static str,
+    pub rules_all_safe: &'static str,
+}
+
+/// Return the catalog in use. This is the classic catalog unless another was selected.
+pub fn text() -> &'static Catalog {
+    CURRENT.get_or_init(|| CATALOGS[0])
+}
+
+/// Select the catalog to use, by name if given, otherwise from the locale in the environment.
+/// Falls back to the classic catalog. Returns false if the named catalog doesn't exist.
+///
+/// This must be called before any text is shown.
+pub fn select_catalog(name: Option<&str>) -> bool {
+    let catalog = match name {
+        Some(name) => match CATALOGS.iter().find(|c| c.name.eq_ignore_ascii_case(name)) {
+            Some(c) => c,
+            None => return false,
+        },
+        None => locale_catalog().unwrap_or(CATALOGS[0]),
+    };
+
+    _ = CURRENT.set(catalog);
+
+    true
+}
+
+/// Return the names of all the catalogs.
+pub fn catalog_names() -> Vec<&'static str> {
+    CATALOGS.iter().map(|c| c.name).collect()
+}
+
+/// Find a catalog for the locale in the environment, e.g. `LANG=en_US.UTF-8`.
+fn locale_catalog() -> Option<&'static Catalog> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|v| std::env::var(v).ok())
+        .find(|v| !v.is_empty())?;
+
+    // Strip off the territory, codeset, and modifier.
+    let language = locale
+        .split(['_', '.', '@'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+
+    CATALOGS
+        .iter()
+        .copied()
+        .find(|c| c.locales.iter().any(|&l| l.eq_ignore_ascii_case(&language)))
+}
+
+/// Fill in the numbered placeholders in a message, e.g. `{0}`, with the given values. This is
+/// done in one pass, so a value that looks like a placeholder, e.g. a player named `{1}`, is left
+/// alone. Placeholders without a value are left as they are.
+pub fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut s = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        s.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let arg = rest
+            .find('}')
+            .and_then(|end| Some((end, args.get(rest[1..end].parse::<usize>().ok()?)?)));
+
+        match arg {
+            Some((end, arg)) => {
+                s.push_str(&arg.to_string());
+                rest = &rest[end + 1..];
+            }
+            None => {
+                s.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    s.push_str(rest);
+
+    s
+}
+
+/// Look up a message in the current catalog, filling in any values.
+///
+/// `msg!(split, name, 2)` is the `split` message with `{0}` and `{1}` filled in.
+macro_rules! msg {
+    ($field:ident) => {
+        $crate::ui::text().$field
+    };
+    ($field:ident, $($arg:expr),+ $(,)?) => {
+        $crate::ui::fill($crate::ui::text().$field, &[$(&$arg),+])
+    };
+}
+
+pub(crate) use msg;
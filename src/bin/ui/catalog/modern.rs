@@ -0,0 +1,228 @@
+//! Modern mixed-case English.
+use super::Catalog;
+
+pub(super) const CATALOG: Catalog = Catalog {
+    name: "modern",
+    locales: &[],
+    uppercase_input: false,
+
+    title: "* S * T * A * R ** L * A * N * E * S *",
+    special_announcement: "Special Announcement!",
+    wizard_mode: "*** Wizard Mode ***",
+    company_names: [
+        "Altair Starways",
+        "Betelgeuse, Ltd.",
+        "Capella Freight Co.",
+        "Denebola Shippers",
+        "Eridani Expediters",
+    ],
+    company_formed: "A new shipping company has been formed!\nIts name is {0}.",
+
+    player_count_prompt: "How many players ({0}-{1})",
+    player_count_invalid: "Please enter a number from {0} to {1}.",
+    player_count_list_prompt: "How many players ({0})",
+    player_count_list_invalid: "Please enter one of {0}.",
+    player_name_prompt: "Player {0}, what is your name",
+    player_team_name_prompt: "Player {0} (team {1}), what is your name",
+    computer_name: "CPU {0}",
+    deciding_first: "\nNow I will decide who goes first...\n",
+    first_player: "{0} is the first player to move.\n",
+
+    map_title: "Map of the Galaxy",
+
+    legal_moves: ", here are your legal moves for this turn:",
+    move_prompt: "What is your move",
+    move_menu_prompt: "What is your move (1-{0}, or row and column)",
+    not_in_list: "That space was not one of your moves. Enter a row and column, e.g. 7E.",
+    survivor_tied: "The following companies are tied for largest:",
+    survivor_prompt: "Which company should survive the merger",
+    hint_outpost: "  {0}  New outpost",
+    hint_growth: "  {0}  {1} grows, stock to ${2}",
+    hint_new_company: "  {0}  Forms {1}, stock at ${2}",
+    hint_merge: "  {0}  {1} merges into {2}, stock to ${3}, your bonus ${4}",
+    hint_split: " after splitting",
+    computer_move: "{0} moves to {1}{2}.",
+    already_rerolled: "You have already drawn new moves this turn.",
+    survivor_invalid: "That company is not one of the choices.",
+
+    loan_status: "Your current loan: ${0}   Credit limit: ${1}",
+    borrow_prompt: "Borrow how much (negative to repay)",
+    over_credit_limit: "You can only borrow ${0}. Try again.",
+    too_little_cash: "You only have ${0}. Try again.",
+    over_repayment: "You only owe ${0}. Try again.",
+    current_cash: "Your current cash: ${0}",
+    give_prompt: "Give how much cash to {0}",
+    buy_prompt: "Buy how many shares of {0} at ${1} (negative to sell)",
+    shares_available: "{0} shares available",
+    you_now_own: "You now own {0} ",
+    quote_cost: "That will cost ${0}",
+    quote_pay: "That will pay ${0}",
+    quote_price: " and move the price to ${0}. OK",
+    too_few_shares: "Only {0} shares are available. Try again.",
+    too_little_stock: "You only have {0} shares. Try again.",
+    computer_trade: "{0} trades {1} shares of {2}.",
+    not_a_number: "Please enter a number.",
+
+    col_player: "Player",
+    col_stock: "Stock",
+    col_size: "Size",
+    col_price_per_share: "Price per share",
+    col_your_holdings: "Your holdings",
+    col_available: "Available",
+    col_teammate_holdings: "Teammate holdings",
+    col_cash: "Cash",
+    col_dividend: "Dividend",
+    col_old_stock: "Old stock",
+    col_new_stock: "New stock",
+    col_total_holdings: "Total holdings",
+    col_bonus_paid: "Bonus paid",
+    col_shares_sold: "Shares sold",
+    col_proceeds: "Proceeds",
+    col_turn: "Turn",
+    col_transaction: "Transaction",
+    col_shares: "Shares",
+    col_loan: "Loan",
+    col_cash_value_of_stock: "Value of stock",
+    col_cash_on_hand: "Cash on hand",
+    col_net_worth: "Net worth",
+    col_team: "Team",
+    col_players: "Players",
+
+    loan_line: "\nLoan: ${0}   Credit limit: ${1}",
+    summary_title: "Where {0} stands:",
+    summary_worth: "Cash: ${0}   Net worth: ${1}",
+
+    split: "The stock of {0} has split {1} for 1!",
+    merged: "{0} has just been merged into {1}!\nPlease note the following transactions.\n",
+    loan_interest: "{0} pays ${1} interest on loans.",
+    loan_called: "The bank has called the loan of {0}!\nThe following stock has been sold.\n",
+    bankrupt: "{0} has gone bankrupt and is out of the game!",
+    pirate_raid: "Pirates have raided the lanes of {0}!\nIts stock has fallen from ${1} to ${2}.",
+    trade_boom: "{0} is enjoying a trade boom!\nIts stock has risen from ${1} to ${2}.",
+    supernova: "The star at {0}{1} has gone supernova!",
+    supernova_company: "The stock of {0} has lost value.",
+    subsidy: "The Galactic Council has subsidized {0}!\n\
+              All shareholders receive ${1} per share.",
+    company_safe: "{0} is now too large to be taken over!",
+    new_president: "{0} is the new president of {1}!",
+    passed: "{0} passes.",
+    rerolled: "{0} pays ${1} for new moves.",
+
+    ledger_purchase: "Purchase",
+    ledger_sale: "Sale",
+    ledger_dividend: "Dividend",
+    ledger_founder_shares: "Founder shares",
+    ledger_merge_bonus: "Merge bonus",
+    ledger_merge_conversion: "Merge of {0}",
+    ledger_split: "Stock split",
+    ledger_subsidy: "Subsidy",
+    ledger_loan: "Loan",
+    ledger_loan_interest: "Loan interest",
+    ledger_forced_sale: "Loan called",
+    ledger_transfer: "Transfer {0}",
+    ledger_reroll_fee: "New moves",
+    ledger_bankruptcy: "Bankruptcy",
+    no_transactions: "No transactions yet.",
+
+    game_over_target_worth: "{0} has reached the target net worth!",
+    game_over_board_covered: "{0} has taken over the galaxy!",
+    game_over_all_safe: "Every company is now too large to be taken over!",
+    game_over_max_rounds: "The last round has been played!",
+    game_over_bankruptcy: "There is no one left to play!",
+    game_over_no_moves: "There are not enough moves left!",
+    final_standings: "The game is over. Here are the final standings.\n",
+    went_bankrupt: "{0} went bankrupt.",
+    owes: "{0} owes ${1}.",
+    play_again: "Another game",
+
+    instructions_prompt: "Does any player need instructions",
+    number_words: [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ],
+    rules_intro: "\n   Star Lanes is a game of interstellar trading. The object\n\
+        of the game is to amass the greatest amount of money. You do\n\
+        this by establishing vast interstellar shipping lanes and\n\
+        buying stock in the companies that control those trade\n\
+        routes. Stock rises in value as the shipping companies grow.\n\
+        Smaller companies can also be merged into larger ones, and\n\
+        stock in the smaller firm is converted into stock in the\n\
+        larger one as described below.\n",
+    rules_moves: "   Each turn, you will be offered {0} spaces to occupy on a\n\
+        {1}x{2} grid (rows 1-{1}, columns A-{3}). After looking at the\n\
+        map of the galaxy, enter the row and column of the space you\n\
+        want, e.g. 7E or 8A. There are four kinds of move.\n",
+    rules_outpost: "   1. Establish an unattached outpost. A space that is not\n\
+        next to a star, another outpost, or a shipping lane becomes\n\
+        an outpost, shown as '+'. You then go on to trade stock as\n\
+        described below.\n",
+    rules_add_lane: "   2. Add to an existing lane. A space next to exactly one\n\
+        shipping lane is added to it and shown with that company's\n\
+        letter. Any stars or outposts next to the space join the\n\
+        lane too. Each new space next to a star adds $500 per share\n\
+        to the company's stock price, and each new outpost adds\n\
+        $100 per share.\n",
+    rules_new_lane: "   3. Establish a new shipping lane. If there are fewer than\n\
+        five lanes, a space next to a star or outpost, but not next\n\
+        to a lane, founds a new company. As its founder you are\n\
+        given 5 free shares. You can then buy stock in it or any\n\
+        other company. Its stock price is set by its stars and\n\
+        spaces as described in #2.\n",
+    rules_merge: "   4. Merge existing companies. A space next to two or more\n\
+        lanes causes a merger: the larger company takes over the\n\
+        smaller. Its stock rises for the spaces and stars it gains,\n\
+        and every player's stock in the smaller company is exchanged\n\
+        for stock in the larger at 2 old shares for 1 new one.",
+    rules_merge_proportional: "Each player is also paid a cash bonus in proportion to\n\
+        their share of the smaller company's stock.",
+    rules_merge_majority: "The president of the smaller company (the player with\n\
+        the most stock) is also paid a bonus of {0} times its share\n\
+        price, and the second-largest shareholder is paid {1} times\n\
+        its share price. Tied players split the bonuses, and no one\n\
+        else receives one.",
+    rules_merge_end: "A company that is taken over can reappear elsewhere on\n\
+        the board when a new company is founded (see #3).\n",
+    rules_merge_choice: "   If the merging companies are the same size, you choose\n\
+        which one takes over the others.\n",
+    rules_merge_price: "   If the merging companies are the same size, the one\n\
+        with the highest stock price takes over the others.\n",
+    rules_merge_random: "   If the merging companies are the same size, the one\n\
+        that takes over the others is chosen at random.\n",
+    rules_dividend_per_size: "   Larger companies pay better dividends: each space in a\n\
+        lane adds {0}% to the dividend rate of its stock.\n",
+    rules_suspend_dividends: "   A company that has just taken over another skips its\n\
+        next dividend.\n",
+    rules_safe_size: "   A company with {0} or more spaces is safe and can never\n\
+        be taken over. Spaces that would merge two safe companies\n\
+        are never offered as moves.\n",
+    rules_hands: "   The spaces you are offered are your hand, and you keep the\n\
+        ones you don't use for your next turn. New spaces are only\n\
+        drawn to replace the one you occupied and any you can no\n\
+        longer use.\n",
+    rules_pass: "   Instead of moving, you can type 'PASS' and go straight on\n\
+        to trading.\n",
+    rules_reroll: "   Once a turn, you can type 'REROLL' and pay ${0} to be\n\
+        offered new spaces instead.\n",
+    rules_dividends_every_move: "   After your move, you are paid dividends of {0}% of the\n\
+        market value of your stock, and then you may",
+    rules_dividends_each_round: "   At the end of each round, every player is paid dividends\n\
+        of {0}% of the market value of their stock. After your\n\
+        move, you may",
+    rules_purchase: "buy or sell stock in any active company. Your stock is\n\
+        counted at market value at the end of the game to decide\n\
+        the winner. If a stock's price goes over $3000, it splits\n\
+        two for one: the price is halved and everyone's shares are\n\
+        doubled.\n",
+    rules_commands: "   At any prompt, type 'STOCK' to see your portfolio, 'MAP'\n\
+        to see the map of the galaxy, or 'LEDGER' to list every\n\
+        purchase, sale, dividend, bonus, and split in your account.\n   \
+        When choosing a move, type 'HINT' to see what each of your\n\
+        legal moves would do.\n",
+    rules_max_rounds: "   The game ends after {0} rounds. The player with the",
+    rules_max_turns: "   The game ends after {0} moves. The player with the",
+    rules_winner: "greatest net worth at that point wins.\n",
+    rules_target: "   The game also ends as soon as a player's net worth\n\
+        reaches ${0}.\n",
+    rules_coverage: "   The game also ends as soon as a company covers {0}%\n\
+        of the galaxy.\n",
+    rules_all_safe: "   The game also ends as soon as every company is safe.\n",
+};
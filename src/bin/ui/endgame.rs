@@ -1,45 +1,150 @@
 //! Game over functionality.
 use crate::UserInterface;
 use crate::ui;
+use starlanes::event::GameOverReason;
 
 impl UserInterface {
+    /// Explain why the game ended. The original game only ended at the turn limit and didn't say
+    /// so. The other endings are new, so even the classic text explains them.
+    fn game_over_reason(&self) {
+        match self.game.get_game_over_reason() {
+            Some(GameOverReason::TargetNetWorth(p)) => {
+                ui::outln!(
+                    "{}",
+                    ui::msg!(game_over_target_worth, self.get_player_name(p))
+                );
+            }
+            Some(GameOverReason::BoardCovered(co_num)) => {
+                ui::outln!(
+                    "{}",
+                    ui::msg!(game_over_board_covered, ui::company_name(co_num))
+                );
+            }
+            Some(GameOverReason::AllCompaniesSafe) => {
+                ui::outln!("{}", ui::msg!(game_over_all_safe));
+            }
+            Some(GameOverReason::MaxRounds) => {
+                ui::outln!("{}", ui::msg!(game_over_max_rounds));
+            }
+            Some(GameOverReason::Bankruptcy) => {
+                ui::outln!("{}", ui::msg!(game_over_bankruptcy));
+            }
+            Some(GameOverReason::NoMovesLeft) => {
+                ui::outln!("{}", ui::msg!(game_over_no_moves));
+            }
+            Some(GameOverReason::MaxTurns) | None => (),
+        }
+    }
+
     /// Print final stats
     pub(crate) fn final_stats(&self) {
-        let companies = self.game.get_companies();
-
         ui::special_announcement();
-        println!("THE GAME IS OVER - HERE ARE THE FINAL STANDINGS\n");
+        self.game_over_reason();
+        ui::outln!("{}", ui::msg!(final_standings));
 
-        println!(
-            "{:<9}{:<23}{:<17}NET WORTH",
-            "PLAYER", "CASH VALUE OF STOCK", "CASH ON HAND"
+        ui::outln!(
+            "{:<9}{:<23}{:<17}{}",
+            ui::msg!(col_player),
+            ui::msg!(col_cash_value_of_stock),
+            ui::msg!(col_cash_on_hand),
+            ui::msg!(col_net_worth)
         );
 
-        for (p, name) in self.names.iter().enumerate() {
+        for p in 0..self.names.len() {
+            let name = self.get_player_table_name(p);
             let player = self.game.get_player(p);
 
-            let mut total_stock_value: i64 = 0;
-            for (i, c) in companies.iter().enumerate().filter(|(_, c)| c.in_use) {
-                let stock_value = player.get_holdings(i) * c.share_price as i64;
-                total_stock_value += stock_value;
-            }
+            let total_stock_value = self.game.get_stock_value(p);
 
             let cash = player.get_cash();
             let cash_str = format!("$ {}", cash);
 
-            let net_worth_str = format!("$ {}", total_stock_value + cash);
+            // Net worth accounts for any outstanding loans.
+            let net_worth_str = format!("$ {}", self.game.get_net_worth(p));
 
-            println!(
+            ui::outln!(
                 "{:<9}{:<23}{:<17}{}",
-                name, total_stock_value, cash_str, net_worth_str
+                name,
+                total_stock_value,
+                cash_str,
+                net_worth_str
+            );
+        }
+
+        let team_count = self.game.get_team_count();
+
+        if team_count > 0 {
+            ui::outln!(
+                "\n{:<9}{:<40}{}",
+                ui::msg!(col_team),
+                ui::msg!(col_players),
+                ui::msg!(col_net_worth)
             );
+
+            // Teams are listed richest first, since that's what decides the game.
+            let mut teams: Vec<usize> = (0..team_count).collect();
+            teams.sort_by_key(|&t| std::cmp::Reverse(self.game.get_team_net_worth(t)));
+
+            for t in teams {
+                let members: Vec<String> = self
+                    .game
+                    .get_team_members(t)
+                    .into_iter()
+                    .map(|p| self.get_player_table_name(p))
+                    .collect();
+
+                ui::outln!(
+                    "{:<9}{:<40}$ {}",
+                    t + 1,
+                    members.join(", "),
+                    self.game.get_team_net_worth(t)
+                );
+            }
+        }
+
+        if self.game.options.loans {
+            ui::outln!();
+            for (p, name) in self.names.iter().enumerate() {
+                let player = self.game.get_player(p);
+
+                if player.is_bankrupt() {
+                    ui::outln!("{}", ui::msg!(went_bankrupt, name));
+                } else if player.get_loan() > 0 {
+                    ui::outln!("{}", ui::msg!(owes, name, player.get_loan()));
+                }
+            }
+        }
+    }
+
+    /// Write the game history to `<prefix>-companies.csv`, `<prefix>-players.csv`, and
+    /// `<prefix>.json`, if requested on the command line.
+    pub(crate) fn write_history(&self) {
+        let Some(prefix) = &self.options.history_prefix else {
+            return;
+        };
+
+        let history = self.game.get_history();
+
+        let files = [
+            (
+                format!("{prefix}-companies.csv"),
+                history.companies_to_csv(),
+            ),
+            (format!("{prefix}-players.csv"), history.players_to_csv()),
+            (format!("{prefix}.json"), history.to_json()),
+        ];
+
+        for (file_name, contents) in files {
+            if let Err(e) = std::fs::write(&file_name, contents) {
+                eprintln!("starlanes: {file_name}: {e}");
+            }
         }
     }
 }
 
 /// Ask for another game
 pub fn play_again() -> bool {
-    print!("ANOTHER GAME");
+    ui::out!("{}", ui::msg!(play_again));
     let yn = ui::input();
 
     yn.starts_with('Y')
@@ -2,57 +2,199 @@
 use crate::UserInterface;
 use crate::ui;
 use starlanes::event::Event;
+use starlanes::map::Point;
 
 impl UserInterface {
     /// Loop through post-move events.
     pub(crate) fn handle_events(&self, events: Vec<Event>) {
-        for event in &events {
+        self.announce_events(&events);
+
+        // The modern UI sums up where things stand after anything happens, except for the end
+        // of the game, which has its own final standings.
+        if ui::is_modern()
+            && !events.is_empty()
+            && !events.iter().any(|e| matches!(e, Event::GameOver(_)))
+        {
+            self.show_summary();
+        }
+    }
+
+    /// Show each event.
+    fn announce_events(&self, events: &[Event]) {
+        for event in events {
             match event {
                 Event::CompanyFormed(c) => {
                     ui::special_announcement();
                     ui::company_formed(*c);
                 }
 
-                Event::Dividends(_) => {
+                Event::Dividends(dividends) => {
                     // Original game didn't display any UI for receiving dividends
-                    //println("Dividends:\n{:#?}", d);
+                    let paid: Vec<_> = dividends.iter().filter(|d| d.amount != 0).collect();
+
+                    if paid.is_empty() {
+                        continue;
+                    }
+
+                    ui::outln!(
+                        "\n{:<9}{:<29}{}",
+                        ui::msg!(col_player),
+                        ui::msg!(col_stock),
+                        ui::msg!(col_dividend)
+                    );
+
+                    for d in paid {
+                        ui::outln!(
+                            "{:<9}{:<29}${}",
+                            self.get_player_table_name(d.player),
+                            ui::company_name(d.company),
+                            ui::format_num_signed(d.amount)
+                        );
+                    }
+                    ui::outln!();
                 }
 
                 Event::Split(co_num, factor) => {
                     ui::special_announcement();
-                    println!(
-                        "THE STOCK OF {} HAS SPLIT {} FOR 1!",
-                        ui::company_name(*co_num),
-                        *factor
-                    );
-                    println!("\n\n\n\n");
+                    ui::outln!("{}", ui::msg!(split, ui::company_name(*co_num), *factor));
+                    ui::end_announcement();
                 }
 
                 Event::Merge(remaining_co, absorbed_co, merge_info) => {
                     ui::special_announcement();
-                    println!(
-                        "{} HAS JUST BEEN MERGED INTO {}!",
-                        ui::company_name(*absorbed_co),
-                        ui::company_name(*remaining_co)
+                    ui::outln!(
+                        "{}",
+                        ui::msg!(
+                            merged,
+                            ui::company_name(*absorbed_co),
+                            ui::company_name(*remaining_co)
+                        )
                     );
-                    println!("PLEASE NOTE THE FOLLOWING TRANSACTIONS.\n");
-                    println!(
-                        "{:9}{:12}{:12}{:19}BONUS PAID",
-                        "PLAYER", "OLD STOCK", "NEW STOCK", "TOTAL HOLDINGS"
+                    ui::outln!(
+                        "{:9}{:12}{:12}{:19}{}",
+                        ui::msg!(col_player),
+                        ui::msg!(col_old_stock),
+                        ui::msg!(col_new_stock),
+                        ui::msg!(col_total_holdings),
+                        ui::msg!(col_bonus_paid)
                     );
 
                     for (i, info) in merge_info.iter().enumerate() {
                         let player = self.game.get_player(i);
-                        println!(
+                        ui::outln!(
                             "{:9}{:12}{:12}{:19} ${}",
-                            self.get_player_name(i),
+                            self.get_player_table_name(i),
                             ui::format_num_signed(info.old_stock),
                             ui::format_num_signed(info.new_stock),
                             ui::format_num_signed(player.get_holdings(*remaining_co)),
                             ui::format_num_signed(info.bonus_paid)
                         );
                     }
-                    println!("\n\n\n\n");
+                    ui::end_announcement();
+                }
+
+                Event::LoanInterest(player, interest) => {
+                    ui::outln!(
+                        "{}",
+                        ui::msg!(loan_interest, self.get_player_name(*player), interest)
+                    );
+                }
+
+                Event::LoanCalled(player, sales) => {
+                    ui::special_announcement();
+                    ui::outln!("{}", ui::msg!(loan_called, self.get_player_name(*player)));
+                    ui::outln!(
+                        "{:<29}{:<20}{}",
+                        ui::msg!(col_stock),
+                        ui::msg!(col_shares_sold),
+                        ui::msg!(col_proceeds)
+                    );
+
+                    for sale in sales {
+                        ui::outln!(
+                            "{:<29}{:<20}${}",
+                            ui::company_name(sale.company),
+                            ui::format_num_signed(sale.shares),
+                            ui::format_num_signed(sale.proceeds)
+                        );
+                    }
+                    ui::end_announcement();
+                }
+
+                Event::Bankrupt(player) => {
+                    ui::special_announcement();
+                    ui::outln!("{}", ui::msg!(bankrupt, self.get_player_name(*player)));
+                    ui::end_announcement();
+                }
+
+                Event::PirateRaid(co_num, old_price, new_price) => {
+                    ui::special_announcement();
+                    ui::outln!(
+                        "{}",
+                        ui::msg!(pirate_raid, ui::company_name(*co_num), old_price, new_price)
+                    );
+                    ui::end_announcement();
+                }
+
+                Event::TradeBoom(co_num, old_price, new_price) => {
+                    ui::special_announcement();
+                    ui::outln!(
+                        "{}",
+                        ui::msg!(trade_boom, ui::company_name(*co_num), old_price, new_price)
+                    );
+                    ui::end_announcement();
+                }
+
+                Event::Supernova(Point(r, c), affected) => {
+                    ui::special_announcement();
+                    ui::outln!(
+                        "{}",
+                        ui::msg!(supernova, r + 1, (b'A' + (*c as u8)) as char)
+                    );
+                    for co_num in affected {
+                        ui::outln!("{}", ui::msg!(supernova_company, ui::company_name(*co_num)));
+                    }
+                    ui::end_announcement();
+                }
+
+                Event::Subsidy(co_num, per_share) => {
+                    ui::special_announcement();
+                    ui::outln!(
+                        "{}",
+                        ui::msg!(subsidy, ui::company_name(*co_num), per_share)
+                    );
+                    ui::end_announcement();
+                }
+
+                Event::Pass(player) => {
+                    ui::outln!("{}", ui::msg!(passed, self.get_player_name(*player)));
+                }
+
+                Event::Reroll(player, fee) => {
+                    ui::outln!("{}", ui::msg!(rerolled, self.get_player_name(*player), fee));
+                }
+
+                Event::GameOver(_) => {
+                    // The reason is shown with the final standings.
+                }
+
+                Event::CompanySafe(co_num) => {
+                    ui::special_announcement();
+                    ui::outln!("{}", ui::msg!(company_safe, ui::company_name(*co_num)));
+                    ui::end_announcement();
+                }
+
+                Event::NewPresident(co_num, player) => {
+                    ui::special_announcement();
+                    ui::outln!(
+                        "{}",
+                        ui::msg!(
+                            new_president,
+                            self.get_player_name(*player),
+                            ui::company_name(*co_num)
+                        )
+                    );
+                    ui::end_announcement();
                 }
             }
         }
@@ -9,18 +9,92 @@ impl UserInterface {
         let companies = self.game.get_companies();
 
         ui::formfeed();
-        println!("\n\n{:<29}{:<20}YOUR HOLDINGS", "STOCK", "PRICE PER SHARE");
+        let limited_shares = self.game.options.limited_shares;
+
+        if limited_shares {
+            ui::outln!(
+                "\n\n{:<29}{:<20}{:<15}{}",
+                ui::msg!(col_stock),
+                ui::msg!(col_price_per_share),
+                ui::msg!(col_your_holdings),
+                ui::msg!(col_available)
+            );
+        } else {
+            ui::outln!(
+                "\n\n{:<29}{:<20}{}",
+                ui::msg!(col_stock),
+                ui::msg!(col_price_per_share),
+                ui::msg!(col_your_holdings)
+            );
+        }
 
         for (i, c) in companies.iter().enumerate() {
             if !c.in_use {
                 continue;
             }
 
-            println!(
-                "{:<29}{: <20}{: }",
-                ui::company_name(i),
-                ui::format_num(c.share_price),
-                ui::format_num_signed(player.get_holdings(i))
+            if limited_shares {
+                ui::outln!(
+                    "{:<29}{: <20}{: <15}{}",
+                    ui::company_name(i),
+                    ui::format_num(c.share_price),
+                    ui::format_num_signed(player.get_holdings(i)),
+                    ui::format_num_signed(c.bank_shares)
+                );
+            } else {
+                ui::outln!(
+                    "{:<29}{: <20}{: }",
+                    ui::company_name(i),
+                    ui::format_num(c.share_price),
+                    ui::format_num_signed(player.get_holdings(i))
+                );
+            }
+        }
+
+        // Teammates can see each other's holdings.
+        let player_num = self.game.get_current_player_index();
+        let teammates = self.game.get_teammates(player_num);
+
+        if !teammates.is_empty() {
+            ui::out!("\n{:<29}", ui::msg!(col_teammate_holdings));
+            for &t in &teammates {
+                ui::out!("{:<12}", self.get_player_table_name(t));
+            }
+            ui::outln!();
+
+            for (i, c) in companies.iter().enumerate() {
+                if !c.in_use {
+                    continue;
+                }
+
+                ui::out!("{:<29}", ui::company_name(i));
+                for &t in &teammates {
+                    ui::out!(
+                        "{:<12}",
+                        ui::format_num_signed(self.game.get_player(t).get_holdings(i))
+                    );
+                }
+                ui::outln!();
+            }
+
+            ui::out!("{:<29}", ui::msg!(col_cash));
+            for &t in &teammates {
+                ui::out!(
+                    "{:<12}",
+                    format!("$ {}", self.game.get_player(t).get_cash())
+                );
+            }
+            ui::outln!();
+        }
+
+        if self.game.options.loans {
+            ui::outln!(
+                "{}",
+                ui::msg!(
+                    loan_line,
+                    player.get_loan(),
+                    self.game.get_credit_limit(player_num)
+                )
             );
         }
     }
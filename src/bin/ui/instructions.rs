@@ -1,98 +1,127 @@
 //! Instructions UI.
 use crate::ui;
+use starlanes::starlanes::{DividendSchedule, MergeBonus, MergeRule, StarLanesOptions};
 
 /// Prompt for and display instructions.
-pub fn prompt_instructions() {
-    print!("DOES ANY PLAYER NEED INSTRUCTIONS");
+pub fn prompt_instructions(options: &StarLanesOptions) {
+    ui::out!("{}", ui::msg!(instructions_prompt));
     let yn = ui::input();
 
-    if &yn[..1] == "Y" {
-        ui::show_instructions();
+    if yn.starts_with('Y') {
+        ui::show_instructions(options);
     }
 }
 
-/// Print the instructions.
-pub fn show_instructions() {
-    println!("\n   STAR LANES IS A GAME OF INTERSTELLAR TRADING.");
-    println!("THE OBJECT OF THE GAME IS TO AMASS THE GREATEST AMOUNT");
-    println!("OF MONEY. THIS IS ACCOMPLISHED BY ESTABLISHING VAST,");
-    println!("INTERSTELLAR SHIPPING LANES, AND PURCHASING STOCK IN");
-    println!("THE COMPANIES THAT CONTROL THOSE TRADE ROUTES. DURING");
-    println!("THE COURSE OF THE GAME, STOCK APPRECIATES IN VALUE AS");
-    println!("THE SHIPPING COMPANIES BECOME LARGER. ALSO, SMALLER");
-    println!("COMPANIES CAN BE MERGED INTO LARGER ONES, AND STOCK");
-    println!("IN THE SMALLER FIRM IS CONVERTED INTO STOCK IN THE ");
-    println!("LARGER ONE AS DESCRIBED BELOW.\n");
-
-    println!("   EACH TURN, THE COMPUTER WILL PRESENT THE PLAYER WITH");
-    println!("FIVE PROSPECTIVE SPACES TO OCCUPY ON A 9X12 MATRIX");
-    println!("(ROWS 1-9, COLUMNS A-L). THE PLAYER, AFTER EXAMINING");
-    println!("THE MAP OF THE GALAXY TO DECIDE WHICH SPACE HE WISHES");
-    println!("TO OCCUPY, RESPONDS WITH THE ROW AND COLUMN OF THAT");
-    println!("SPACE, I.E., 7E, 8A, ETC. THERE ARE FOUR POSSIBLE");
-    println!("MOVES A PLAYER CAN MAKE.\n");
-
-    println!("   1. HE CAN ESTABLISH AN UNATTACHED OUTPOST- IF HE");
-    println!("SELECTS A SPACE THAT IS NOT ADJACENT TO A STAR, ANOTHER");
-    println!("UNATTACHED OUTPOST, OR AN EXISTING SHIPPING LANE, THIS");
-    println!("SPACE WILL BE DESIGNATED WITH A '+'. HE WILL THEN PROCEED");
-    println!("WITH STOCK TRANSACTIONS, AS LISTED BELOW.\n");
-
-    println!("   2. HE CAN ADD TO AN EXISTING LANE- IF HE SELECTS A SPACE");
-    println!("WHICH IS ADJACENT TO ONE - AND ONLY ONE EXISTING SHIPPING");
-    println!("LANE, THE SPACE HE SELECTS WILL BE ADDED TO THAT SHIPPING");
-    println!("LANE, AND WILL BE DESIGNATED WITH THE FIRST LETTER OF ");
-    println!("THE COMPANY THAT OWNS THAT LANE. IF THERE ARE ANY STARS");
-    println!("OR UNATTACHED OUTPOSTS ALSO ADJACENT TO THE SELECTED SPACE,");
-    println!("THEY, TOO, WILL BE INCORPORATED INTO THE EXISTING LANE.");
-    println!("EACH NEW SQUARE ADJACENT TO A STAR ADDS $500 PER SHARE, AND");
-    println!("EACH NEW OUTPOST ADDS $100 PER SHARE TO THE MARKET VALUE");
-    println!("OF THE STOCK OF THAT SHIPPING COMPANY.\n");
-
-    println!("   3. HE MAY ESTABLISH A NEW SHIPPING LANE- IF THERE");
-    println!("ARE FIVE OR LESS EXISTING SHIPPING LANES ESTABLISHED,");
-    println!("THE PLAYER MAY, GIVEN THE PROPER SPACE TO PLAY, ESTABLISH");
-    println!("A NEW SHIPPING LANE. HE MAY DO THIS BY OCCUPYING A SPACE");
-    println!("ADJACENT TO A STAR OR ANOTHER UNATTACHED OUTPOST, BUT ");
-    println!("NOT ADJACENT TO AN EXISTING SHIPPING LANE. IF HE ");
-    println!("ESTABLISHES A NEW SHIPPING LANE, HE IS AUTOMATICALLY");
-    println!("ISSUED 5 SHARES IN THE NEW COMPANY AS A REWARD. HE");
-    println!("MAY THEN PROCEED TO BUY STOCK IN THAT COMPANY, OR ANY");
-    println!("OTHER ACTIVE COMPANY, AS DESCRIBED BELOW. THE MARKET ");
-    println!("VALUE OF THE NEW STOCK IS ESTABLISHED BY THE NUMBER OF");
-    println!("STARS AND OCCUPIED SPACES AS DESCRIBED IN #2 ABOVE.\n");
-
-    println!("   4. HE MAY MERGE TWO EXISTING COMPANIES- IF PLAYER");
-    println!("SELECTS A SPACE ADJACENT TO TWO EXISTING SHIPPING");
-    println!("LANES, A MERGER OCCURS. THE LARGER COMPANY TAKES OVER");
-    println!("THE SMALLER COMPANY, THE STOCK OF THE LARGER COMPANY IS");
-    println!("INCREASED IN VALUE ACCORDING TO THE NUMBER OF SPACES AND");
-    println!("STARS ADDED TO ITS LANE, EACH PLAYER'S STOCK IN THE");
-    println!("SMALLER COMPANY IS EXCHANGED FOR SHARES IN THE LARGER");
-    println!("ON A RATIO OF 2 SHARES OF THE SMALLER = 1 SHARE OF THE");
-    println!("LARGER. ALSO, EACH PLAYER IS PAID A CASH BONUS PROPORTIONAL");
-    println!("TO THE PERCENTAGE OF OUTSTANDING STOCK HE HELD IN THE");
-    println!("SMALLER COMPANY. NOTE: AFTER A COMPANY BECOMES DEFUNCT");
-    println!("THROUGH THIS MERGER PROCESS, IT CAN REAPPEAR ELSEWHERE");
-    println!("ON THE BOARD IF A NEW COMPANY IS ESTABLISHED (SEE #3 ABOVE)\n");
-
-    println!("   NEXT THE COMPUTER ADDS STOCK DIVIDENDS TO THE");
-    println!("PLAYER'S CASH ON HAND (5% OF THE MARKET VALUE OF THE ");
-    println!("STOCK IN HIS POSSESSION), AND OFFERS HIM THE OPPORTUNITY TO");
-    println!("PURCHASE STOCK IN ANY OF THE ACTIVE COMPANIES ON THE");
-    println!("BOARD. STOCK MAY NOT BE SOLD, BUT THE MARKET VALUES OF");
-    println!("EACH PLAYER'S STOCK IS TAKEN INTO ACCOUNT AT THE END");
-    println!("OF THE GAME TO DETERMINE THE WINNER. IF THE MARKET VALUE");
-    println!("OF A GIVEN STOCK EXCEEDS $3000 AT ANY TIME DURING THE ");
-    println!("GAME, THAT STOCK SPLITS TWO FOR ONE. THE PRICE IS CUT");
-    println!("IN HALF, AND THE NUMBER OF SHARES OWNED BY EACH PLAYER");
-    println!("IS DOUBLED.\n");
-
-    println!("NOTE: THE PLAYER MAY LOOK AT HIS PORTFOLIO AT ANY TIME");
-    println!("DURING THE COURSE OF HIS TURN BY RESPONDING WITH 'STOCK'");
-    println!("TO AN INPUT STATEMENT. LIKEWISE, HE CAN REVIEW THE MAP");
-    println!("OF THE GALAXY BY TYPING 'MAP' TO AN INPUT STATEMENT.\n");
-
-    println!("GAME ENDS AFTER 48 MOVES. PLAYER WITH THE GREATEST");
-    println!("NET WORTH AT THAT POINT IS THE WINNER.\n\n");
+/// Return a small number as a word, e.g. `FIVE`.
+fn number_word(n: usize) -> String {
+    ui::text()
+        .number_words
+        .get(n)
+        .map_or(n.to_string(), |w| w.to_string())
+}
+
+/// Print the instructions for the rules selected in the game options.
+pub fn show_instructions(options: &StarLanesOptions) {
+    ui::outln!("{}", ui::msg!(rules_intro));
+
+    let (width, height) = options.get_map_size();
+    ui::outln!(
+        "{}",
+        ui::msg!(
+            rules_moves,
+            number_word(options.get_candidate_move_count()),
+            height,
+            width,
+            (b'A' + (width - 1) as u8) as char
+        )
+    );
+
+    ui::outln!("{}", ui::msg!(rules_outpost));
+    ui::outln!("{}", ui::msg!(rules_add_lane));
+    ui::outln!("{}", ui::msg!(rules_new_lane));
+
+    ui::outln!("{}", ui::msg!(rules_merge));
+    match options.merge_bonus {
+        MergeBonus::Proportional => ui::outln!("{}", ui::msg!(rules_merge_proportional)),
+        MergeBonus::Majority => ui::outln!(
+            "{}",
+            ui::msg!(
+                rules_merge_majority,
+                options.majority_bonus_factor,
+                options.minority_bonus_factor
+            )
+        ),
+    }
+    ui::outln!("{}", ui::msg!(rules_merge_end));
+
+    match options.merge_rule {
+        MergeRule::Classic => (),
+        MergeRule::PlayerChoice => ui::outln!("{}", ui::msg!(rules_merge_choice)),
+        MergeRule::HighestPrice => ui::outln!("{}", ui::msg!(rules_merge_price)),
+        MergeRule::Random => ui::outln!("{}", ui::msg!(rules_merge_random)),
+    }
+
+    if options.dividend_percentage_per_size > 0.0 {
+        ui::outln!(
+            "{}",
+            ui::msg!(
+                rules_dividend_per_size,
+                options.dividend_percentage_per_size
+            )
+        );
+    }
+
+    if options.suspend_dividends_after_merge {
+        ui::outln!("{}", ui::msg!(rules_suspend_dividends));
+    }
+
+    if let Some(safe_size) = options.safe_company_size {
+        ui::outln!("{}", ui::msg!(rules_safe_size, safe_size));
+    }
+
+    if options.persistent_hands {
+        ui::outln!("{}", ui::msg!(rules_hands));
+    }
+
+    if options.allow_pass {
+        ui::outln!("{}", ui::msg!(rules_pass));
+    }
+
+    if let Some(fee) = options.reroll_fee {
+        ui::outln!("{}", ui::msg!(rules_reroll, fee));
+    }
+
+    match options.dividend_schedule {
+        DividendSchedule::EveryMove => ui::outln!(
+            "{}",
+            ui::msg!(rules_dividends_every_move, options.dividend_percentage)
+        ),
+        DividendSchedule::EachRound => ui::outln!(
+            "{}",
+            ui::msg!(rules_dividends_each_round, options.dividend_percentage)
+        ),
+    }
+    ui::outln!("{}", ui::msg!(rules_purchase));
+
+    ui::outln!("{}", ui::msg!(rules_commands));
+
+    match options.max_rounds {
+        Some(max_rounds) => ui::outln!("{}", ui::msg!(rules_max_rounds, max_rounds)),
+        None => ui::outln!("{}", ui::msg!(rules_max_turns, options.get_max_turns())),
+    }
+    ui::outln!("{}", ui::msg!(rules_winner));
+
+    if let Some(target) = options.target_net_worth {
+        ui::outln!("{}", ui::msg!(rules_target, target));
+    }
+
+    if let Some(percentage) = options.board_coverage_percentage {
+        ui::outln!("{}", ui::msg!(rules_coverage, percentage));
+    }
+
+    if options.end_when_all_safe {
+        ui::outln!("{}", ui::msg!(rules_all_safe));
+    }
+
+    ui::outln!();
 }
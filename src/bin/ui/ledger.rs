@@ -0,0 +1,87 @@
+//! Show ledger UI.
+use crate::UserInterface;
+use crate::ui;
+use starlanes::player::LedgerReason;
+
+impl UserInterface {
+    /// Describe a ledger reason.
+    fn ledger_reason(&self, reason: LedgerReason) -> String {
+        match reason {
+            LedgerReason::Purchase => ui::msg!(ledger_purchase).to_string(),
+            LedgerReason::Sale => ui::msg!(ledger_sale).to_string(),
+            LedgerReason::Dividend => ui::msg!(ledger_dividend).to_string(),
+            LedgerReason::FounderShares => ui::msg!(ledger_founder_shares).to_string(),
+            LedgerReason::MergeBonus(_) => ui::msg!(ledger_merge_bonus).to_string(),
+            LedgerReason::MergeConversion(co_num) => ui::msg!(
+                ledger_merge_conversion,
+                Self::company_to_char(co_num as u32)
+            ),
+            LedgerReason::Split => ui::msg!(ledger_split).to_string(),
+            LedgerReason::Subsidy => ui::msg!(ledger_subsidy).to_string(),
+            LedgerReason::Loan => ui::msg!(ledger_loan).to_string(),
+            LedgerReason::LoanInterest => ui::msg!(ledger_loan_interest).to_string(),
+            LedgerReason::ForcedSale => ui::msg!(ledger_forced_sale).to_string(),
+            LedgerReason::Transfer(p) => ui::msg!(ledger_transfer, self.get_player_name(p)),
+            LedgerReason::RerollFee => ui::msg!(ledger_reroll_fee).to_string(),
+            LedgerReason::Bankruptcy => ui::msg!(ledger_bankruptcy).to_string(),
+        }
+    }
+
+    /// Show the ledger of every cash and stock movement for the current player.
+    pub(crate) fn show_ledger(&self) {
+        let ledger = self.game.get_current_player().get_ledger();
+
+        ui::formfeed();
+
+        // Debt only gets a column if there can be any.
+        let loans = self.game.options.loans;
+
+        ui::out!(
+            "\n\n{:<6}{:<24}{:<25}{:<9}",
+            ui::msg!(col_turn),
+            ui::msg!(col_transaction),
+            ui::msg!(col_stock),
+            ui::msg!(col_shares)
+        );
+        if loans {
+            ui::outln!("{:<14}{}", ui::msg!(col_cash), ui::msg!(col_loan));
+        } else {
+            ui::outln!("{}", ui::msg!(col_cash));
+        }
+
+        for entry in ledger {
+            let company = entry.company.map_or("", ui::company_name);
+
+            let shares = if entry.shares != 0 {
+                entry.shares.to_string()
+            } else {
+                String::new()
+            };
+
+            let cash = if entry.cash != 0 {
+                format!("$ {}", entry.cash)
+            } else {
+                String::new()
+            };
+
+            ui::out!(
+                "{:<6}{:<24}{:<25}{:<9}",
+                entry.turn + 1,
+                self.ledger_reason(entry.reason),
+                company,
+                shares
+            );
+            if loans && entry.loan != 0 {
+                ui::outln!("{:<14}$ {}", cash, entry.loan);
+            } else {
+                ui::outln!("{cash}");
+            }
+        }
+
+        if ledger.is_empty() {
+            ui::outln!("{}", ui::msg!(no_transactions));
+        }
+
+        ui::outln!();
+    }
+}
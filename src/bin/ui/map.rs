@@ -3,9 +3,12 @@ use crate::UserInterface;
 use crate::ui;
 use starlanes::map::MapCell::*;
 
+/// Width of the original game's map, which has its column header written out in full.
+const CLASSIC_MAP_WIDTH: usize = 12;
+
 impl UserInterface {
     /// Map a company number to a map character.
-    fn company_to_char(id: u32) -> char {
+    pub(crate) fn company_to_char(id: u32) -> char {
         match id {
             0..5 => (b'A' + id as u8) as char,
             _ => panic!("unknown company id: {}", id),
@@ -18,12 +21,21 @@ impl UserInterface {
 
         ui::formfeed();
 
-        println!("\n{}MAP OF THE GALAXY", ui::tab(22));
-        println!("{}*******************", ui::tab(21));
-        println!("{} A  B  C  D  E  F  G  H  I  J  K  L", ui::tab(12));
+        let title = ui::msg!(map_title);
+        ui::outln!("\n{}{}", ui::tab(22), title);
+        ui::outln!("{}{}", ui::tab(21), "*".repeat(title.chars().count() + 2));
+        if map.width == CLASSIC_MAP_WIDTH {
+            ui::outln!("{} A  B  C  D  E  F  G  H  I  J  K  L", ui::tab(12));
+        } else {
+            let letters: Vec<String> = (0..map.width)
+                .map(|c| format!(" {}", (b'A' + c as u8) as char))
+                .collect();
+            ui::outln!("{}{}", ui::tab(12), letters.join(" "));
+        }
 
         for r in 0..map.height {
-            print!("{} {} ", ui::tab(9), r + 1);
+            // Keep the columns lined up on maps with more than 9 rows.
+            ui::out!("{}{:>2} ", ui::tab(9), r + 1);
             for c in 0..map.width {
                 let cell = map.get(r, c);
 
@@ -34,9 +46,9 @@ impl UserInterface {
                     Company(id) => Self::company_to_char(id),
                 };
 
-                print!(" {character} ");
+                ui::out!(" {character} ");
             }
-            println!();
+            ui::outln!();
         }
     }
 }
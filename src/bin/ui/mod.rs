@@ -1,17 +1,21 @@
 //! Various bits of UI code.
 mod basicio;
+mod catalog;
 mod company;
 mod endgame;
 mod event;
 mod holdings;
 mod instructions;
+mod ledger;
 mod map;
 mod movefuncs;
 mod player;
+mod summary;
 mod trade;
 mod util;
 
 pub use basicio::*;
+pub use catalog::*;
 pub use company::*;
 pub use endgame::*;
 pub use instructions::*;
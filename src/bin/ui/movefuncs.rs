@@ -1,13 +1,80 @@
 //! Getting and making moves.
 use crate::UserInterface;
 use crate::ui;
+use starlanes::event::Event;
 use starlanes::map::Point;
+use starlanes::starlanes::{CompanyPreview, MovePreview, RerollError};
+
+/// What the player chose to do in the move phase.
+enum MoveChoice {
+    /// Occupy a space.
+    Place(Point),
+    /// Pass instead of moving.
+    Pass,
+    /// Pay to draw new moves.
+    Reroll,
+}
 
 impl UserInterface {
-    /// Have the user select a move from a group of candidates.
-    pub(crate) fn get_move(&self, candidates: &[Point]) -> Point {
+    /// Run the move phase: have the user choose a move and make it, drawing new moves first if
+    /// they pay to reroll. Returns the events from the move, or `None` if the game ended because
+    /// there weren't enough moves left.
+    pub(crate) fn move_phase(&mut self) -> Option<Vec<Event>> {
+        loop {
+            let candidates = self.game.get_moves();
+
+            // This can happen if there aren't enough moves remaining.
+            if self.game.game_is_over() {
+                return None;
+            }
+
+            match self.get_move(&candidates) {
+                MoveChoice::Place(move_point) => {
+                    let events = self.game.make_move(move_point);
+
+                    if self.game.must_choose_survivor() {
+                        return Some(self.choose_survivor());
+                    }
+
+                    return Some(events);
+                }
+
+                MoveChoice::Pass => return Some(self.game.pass_move()),
+
+                MoveChoice::Reroll => match self.game.reroll() {
+                    Ok(events) => self.handle_events(events),
+
+                    Err(RerollError::AlreadyRerolled) => {
+                        ui::outln!("{}", ui::msg!(already_rerolled));
+                    }
+
+                    Err(RerollError::TooLittleCash) => {
+                        let cash = self.game.get_current_player().get_cash();
+                        ui::outln!("{}", ui::msg!(too_little_cash, cash));
+                    }
+                },
+            }
+        }
+    }
+
+    /// Have the user select a move from a group of candidates, or pass or reroll if the options
+    /// allow.
+    fn get_move(&self, candidates: &[Point]) -> MoveChoice {
+        if self.is_computer(self.game.get_current_player_index()) {
+            let move_point @ Point(r, c) = self.game.computer_move();
+            let col = (b'A' + (c as u8)) as char;
+
+            ui::outln!(
+                "\n{}",
+                ui::msg!(computer_move, self.get_current_player_name(), r + 1, col)
+            );
+
+            return MoveChoice::Place(move_point);
+        }
+
         // There is a bug in the original source where the name wasn't printed again if a 'M'ap or
-        // 'S'tocks were requested. This horrid thing recreates that bug.
+        // 'S'tocks were requested. This horrid thing recreates that bug, except in the modern UI.
+        let modern = ui::is_modern();
         let mut bug_first = true;
         let mut show_error = false;
 
@@ -15,25 +82,43 @@ impl UserInterface {
 
         loop {
             if show_error {
-                println!("THAT SPACE WAS NOT INCLUDED IN THE LIST...");
+                ui::outln!("{}", ui::msg!(not_in_list));
                 show_error = false;
             } else {
-                if bug_first {
-                    print!("\n{name}");
+                if bug_first || modern {
+                    ui::out!("\n{name}");
                     bug_first = false;
                 }
-                println!(", HERE ARE YOUR LEGAL MOVES FOR THIS TURN:");
+                ui::outln!("{}", ui::msg!(legal_moves));
 
-                for &Point(r, c) in candidates {
-                    print!(" {} {} /", r + 1, (b'A' + (c as u8)) as char);
+                for (i, &Point(r, c)) in candidates.iter().enumerate() {
+                    let col = (b'A' + (c as u8)) as char;
+
+                    if modern {
+                        ui::out!("  {}) {}{}", i + 1, r + 1, col);
+                    } else {
+                        ui::out!(" {} {} /", r + 1, col);
+                    }
                 }
-                println!();
+                ui::outln!();
             }
 
-            print!("WHAT IS YOUR MOVE");
+            if modern {
+                ui::out!("{}", ui::msg!(move_menu_prompt, candidates.len()));
+            } else {
+                ui::out!("{}", ui::msg!(move_prompt));
+            }
 
             let input = ui::input();
 
+            // The modern UI numbers the moves.
+            if modern
+                && let Ok(n) = input.parse::<usize>()
+                && (1..=candidates.len()).contains(&n)
+            {
+                return MoveChoice::Place(candidates[n - 1]);
+            }
+
             if input.is_empty() {
                 show_error = true;
                 continue;
@@ -49,22 +134,44 @@ impl UserInterface {
                 continue;
             }
 
+            if input.starts_with('L') {
+                self.show_ledger();
+                continue;
+            }
+
+            if input.starts_with('H') {
+                self.show_hints(candidates);
+                continue;
+            }
+
+            if input.starts_with('P') && self.game.options.allow_pass {
+                return MoveChoice::Pass;
+            }
+
+            if input.starts_with('R') && self.game.options.reroll_fee.is_some() {
+                return MoveChoice::Reroll;
+            }
+
             if input.len() < 2 {
                 show_error = true;
                 continue;
             }
 
-            let (row_str, col_str) = input.split_at(input.len() - 1);
+            let Some((col_at, col_char)) = input.char_indices().last() else {
+                show_error = true;
+                continue;
+            };
+
+            let row = input[..col_at].trim().parse::<usize>().ok();
 
-            let selrow = match row_str.trim().parse::<usize>() {
-                Ok(n) => n - 1,
-                _ => {
+            let selrow = match row.and_then(|n| n.checked_sub(1)) {
+                Some(n) => n,
+                None => {
                     show_error = true;
                     continue;
                 }
             };
 
-            let col_char = col_str.chars().next().unwrap();
             let selcol = match col_char {
                 'A'..='Z' => (col_char as u8 - b'A') as usize,
                 _ => {
@@ -75,11 +182,144 @@ impl UserInterface {
 
             let selpoint = Point(selrow, selcol);
 
-            if self.wizard_mode() || candidates.contains(&selpoint) {
-                return selpoint;
+            if candidates.contains(&selpoint) {
+                return MoveChoice::Place(selpoint);
+            }
+
+            // Wizards can move anywhere that's on the map and playable.
+            if self.wizard_mode()
+                && selrow < self.game.map.height
+                && selcol < self.game.map.width
+                && self.game.is_playable(selpoint)
+            {
+                return MoveChoice::Place(selpoint);
             }
 
             show_error = true;
         }
     }
+
+    /// Show what each of the candidate moves would do.
+    fn show_hints(&self, candidates: &[Point]) {
+        let player_num = self.game.get_current_player_index();
+
+        // Prices include any splits, which get a note.
+        let price = |company: &CompanyPreview| {
+            if company.splits > 0 {
+                format!("{}{}", company.share_price, ui::msg!(hint_split))
+            } else {
+                company.share_price.to_string()
+            }
+        };
+
+        ui::outln!();
+
+        for &move_point @ Point(r, c) in candidates {
+            let at = format!("{}{}", r + 1, (b'A' + (c as u8)) as char);
+
+            match self.game.preview_move(move_point) {
+                MovePreview::Outpost => ui::outln!("{}", ui::msg!(hint_outpost, at)),
+
+                MovePreview::Growth(company) => ui::outln!(
+                    "{}",
+                    ui::msg!(
+                        hint_growth,
+                        at,
+                        ui::company_name(company.company),
+                        price(&company)
+                    )
+                ),
+
+                MovePreview::NewCompany(company) => ui::outln!(
+                    "{}",
+                    ui::msg!(
+                        hint_new_company,
+                        at,
+                        ui::company_name(company.company),
+                        price(&company)
+                    )
+                ),
+
+                // A tie that the player or chance breaks gets a line for each possible survivor.
+                MovePreview::Merge(merges) => {
+                    for merge in merges {
+                        let absorbed: Vec<&str> = merge
+                            .absorbed
+                            .iter()
+                            .map(|&(co_num, _)| ui::company_name(co_num))
+                            .collect();
+
+                        let bonus: i64 = merge
+                            .absorbed
+                            .iter()
+                            .map(|(_, info)| info[player_num].bonus_paid)
+                            .sum();
+
+                        ui::outln!(
+                            "{}",
+                            ui::msg!(
+                                hint_merge,
+                                at,
+                                absorbed.join(" & "),
+                                ui::company_name(merge.survivor.company),
+                                price(&merge.survivor),
+                                bonus
+                            )
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Have the user choose which of the tied companies survives a merge, and complete the move.
+    pub(crate) fn choose_survivor(&mut self) -> Vec<Event> {
+        if self.is_computer(self.game.get_current_player_index()) {
+            let co_num = self.game.computer_survivor();
+            return self.game.choose_survivor(co_num);
+        }
+
+        let choices = self.game.get_survivor_choices();
+
+        loop {
+            ui::outln!("{}", ui::msg!(survivor_tied));
+            for &co_num in &choices {
+                ui::outln!(
+                    "{}{}  {}",
+                    ui::tab(5),
+                    Self::company_to_char(co_num as u32),
+                    ui::company_name(co_num)
+                );
+            }
+
+            ui::out!("{}", ui::msg!(survivor_prompt));
+
+            let input = ui::input();
+
+            if input.starts_with("MAP") {
+                self.display_map();
+                continue;
+            }
+
+            if input.starts_with("STOCK") {
+                self.show_holdings();
+                continue;
+            }
+
+            if input.starts_with("LEDGER") {
+                self.show_ledger();
+                continue;
+            }
+
+            let choice = choices
+                .iter()
+                .copied()
+                .find(|&co_num| input.starts_with(Self::company_to_char(co_num as u32)));
+
+            match choice {
+                Some(co_num) => return self.game.choose_survivor(co_num),
+                None => ui::outln!("{}", ui::msg!(survivor_invalid)),
+            }
+        }
+    }
 }
@@ -1,31 +1,107 @@
 //! Player-related functions.
 use crate::UserInterface;
 use crate::ui;
+use starlanes::starlanes::{MAX_PLAYERS, StarLanes};
+
+/// Widest a player name can be in a table column.
+const TABLE_NAME_WIDTH: usize = 8;
+
+/// Most players seated before names are cut short in tables.
+const TABLE_NAME_MAX_PLAYERS: usize = 4;
 
 impl UserInterface {
-    /// Prompt for and get the player count.
+    /// Prompt for and get the player count, unless it was given in the options.
     pub(crate) fn get_player_count(&mut self) {
-        print!("HOW MANY PLAYERS (2-4)");
-        let count = ui::input();
-        self.player_count = count.parse().unwrap();
+        if let Some(count) = self.options.player_count {
+            self.player_count = count;
+            self.game.options.player_count = count;
+            return;
+        }
+
+        // Team games need a whole number of teams, so they list the counts that work.
+        let team_size = self.game.options.team_size;
+        let counts: Vec<usize> = (2..=MAX_PLAYERS)
+            .filter(|&count| StarLanes::team_player_count_is_valid(count, team_size))
+            .collect();
+        let list = counts
+            .iter()
+            .map(|count| count.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        loop {
+            match team_size {
+                Some(_) => ui::out!("{}", ui::msg!(player_count_list_prompt, list)),
+                None => ui::out!("{}", ui::msg!(player_count_prompt, 2, MAX_PLAYERS)),
+            }
+
+            match ui::input().parse() {
+                Ok(count) if counts.contains(&count) => {
+                    self.player_count = count;
+                    break;
+                }
+                _ => match team_size {
+                    Some(_) => ui::outln!("{}", ui::msg!(player_count_list_invalid, list)),
+                    None => ui::outln!("{}", ui::msg!(player_count_invalid, 2, MAX_PLAYERS)),
+                },
+            }
+        }
+
         self.game.options.player_count = self.player_count;
     }
 
-    /// Get the player names.
+    /// Get the player names. Names given in the options aren't asked for, and computer players
+    /// are named after their seat unless given a name.
     pub(crate) fn get_player_names(&mut self) {
         self.names.clear();
 
         for i in 1..=self.player_count {
-            print!("PLAYER {i} WHAT IS YOUR NAME");
-            self.names.push(ui::input())
+            if let Some(name) = self.options.names.as_ref().and_then(|n| n.get(i - 1)) {
+                if ui::text().uppercase_input {
+                    self.names.push(name.to_uppercase());
+                } else {
+                    self.names.push(name.clone());
+                }
+                continue;
+            }
+
+            if self.is_computer(i - 1) {
+                self.names.push(ui::msg!(computer_name, i));
+                continue;
+            }
+
+            match self.game.get_team(i - 1) {
+                Some(team) => ui::out!("{}", ui::msg!(player_team_name_prompt, i, team + 1)),
+                None => ui::out!("{}", ui::msg!(player_name_prompt, i)),
+            }
+            self.names.push(ui::input_text())
         }
     }
 
+    /// Returns true if a player's seat is played by the computer.
+    pub(crate) fn is_computer(&self, player_num: usize) -> bool {
+        self.options
+            .computer
+            .as_ref()
+            .is_some_and(|seats| seats.contains(&(player_num + 1)))
+    }
+
     /// Return a particular player's name.
     pub(crate) fn get_player_name(&self, n: usize) -> &String {
         &self.names[n]
     }
 
+    /// Return a particular player's name, cut short to fit in a table column when more players
+    /// are seated than the original game allowed. This keeps tables readable with a full house
+    /// of players, while games of up to 4 show names in full, as the original did.
+    pub(crate) fn get_player_table_name(&self, n: usize) -> String {
+        if self.names.len() > TABLE_NAME_MAX_PLAYERS {
+            self.names[n].chars().take(TABLE_NAME_WIDTH).collect()
+        } else {
+            self.names[n].clone()
+        }
+    }
+
     /// Return the current player name.
     pub(crate) fn get_current_player_name(&self) -> &String {
         &self.names[self.game.get_current_player_index()]
@@ -35,11 +111,8 @@ impl UserInterface {
     ///
     /// The game has already decided this, so it's just informational.
     pub(crate) fn go_first_message(&self) {
-        println!("\nNOW I WILL DECIDED WHO GOES FIRST...\n"); // DECIDED sic
+        ui::outln!("{}", ui::msg!(deciding_first));
 
-        println!(
-            "{} IS THE FIRST PLAYER TO MOVE.\n",
-            self.get_current_player_name()
-        );
+        ui::outln!("{}", ui::msg!(first_player, self.get_current_player_name()));
     }
 }
@@ -0,0 +1,47 @@
+//! Summary screen for the modern UI.
+use crate::UserInterface;
+use crate::ui;
+
+impl UserInterface {
+    /// Show where the current player stands: every company in play, and their cash and net
+    /// worth.
+    pub(crate) fn show_summary(&self) {
+        let player_num = self.game.get_current_player_index();
+        let player = self.game.get_player(player_num);
+
+        ui::outln!(
+            "\n{}",
+            ui::msg!(summary_title, self.get_player_name(player_num))
+        );
+        ui::outln!(
+            "{:<29}{:<8}{:<20}{}",
+            ui::msg!(col_stock),
+            ui::msg!(col_size),
+            ui::msg!(col_price_per_share),
+            ui::msg!(col_your_holdings)
+        );
+
+        for (i, c) in self.game.get_companies().iter().enumerate() {
+            if !c.in_use {
+                continue;
+            }
+
+            ui::outln!(
+                "{:<29}{:<8}{:<20}{}",
+                ui::company_name(i),
+                c.size,
+                format!("$ {}", c.share_price),
+                player.get_holdings(i)
+            );
+        }
+
+        ui::outln!(
+            "{}\n",
+            ui::msg!(
+                summary_worth,
+                player.get_cash(),
+                self.game.get_net_worth(player_num)
+            )
+        );
+    }
+}
@@ -1,11 +1,160 @@
 //! Trading functions.
 use crate::UserInterface;
 use crate::ui;
-use starlanes::starlanes::TradeError;
+use starlanes::starlanes::{LoanError, PriceCurve, TradeError, TransferError};
 
 impl UserInterface {
+    /// Borrow or repay cash, if loans are enabled. This happens before the stock trading.
+    fn borrow(&mut self) {
+        if !self.game.options.loans {
+            return;
+        }
+
+        let player_num = self.game.get_current_player_index();
+
+        loop {
+            let player = self.game.get_current_player();
+            let loan = player.get_loan();
+            let cash = player.get_cash();
+            let credit_limit = self.game.get_credit_limit(player_num);
+
+            ui::outln!("{}", ui::msg!(loan_status, loan, credit_limit));
+            ui::out!("{}", ui::msg!(borrow_prompt));
+            let to_borrow = ui::input();
+
+            if to_borrow.starts_with('M') {
+                self.display_map();
+                continue;
+            }
+
+            if to_borrow.starts_with('S') {
+                self.show_holdings();
+                continue;
+            }
+
+            if to_borrow.starts_with('L') {
+                self.show_ledger();
+                continue;
+            }
+
+            let Some(to_borrow) = ui::parse_amount(&to_borrow) else {
+                ui::outln!("{}", ui::msg!(not_a_number));
+                continue;
+            };
+
+            match self.game.borrow(to_borrow) {
+                Err(LoanError::OverCreditLimit) => {
+                    ui::outln!("{}", ui::msg!(over_credit_limit, credit_limit));
+                }
+
+                Err(LoanError::TooLittleCash) => {
+                    ui::outln!("{}", ui::msg!(too_little_cash, cash));
+                }
+
+                Err(LoanError::OverRepayment) => {
+                    ui::outln!("{}", ui::msg!(over_repayment, loan));
+                }
+
+                Ok(_) => break,
+            }
+        }
+    }
+
+    /// Give cash to teammates, if team transfers are enabled.
+    fn transfer(&mut self) {
+        if !self.game.options.team_transfers {
+            return;
+        }
+
+        let player_num = self.game.get_current_player_index();
+
+        for teammate in self.game.get_teammates(player_num) {
+            if self.game.get_player(teammate).is_bankrupt() {
+                continue;
+            }
+
+            loop {
+                let cash = self.game.get_current_player().get_cash();
+
+                ui::outln!("{}", ui::msg!(current_cash, cash));
+                ui::out!("{}", ui::msg!(give_prompt, self.get_player_name(teammate)));
+                let to_give = ui::input();
+
+                if to_give.starts_with('M') {
+                    self.display_map();
+                    continue;
+                }
+
+                if to_give.starts_with('S') {
+                    self.show_holdings();
+                    continue;
+                }
+
+                if to_give.starts_with('L') {
+                    self.show_ledger();
+                    continue;
+                }
+
+                let Some(to_give) = ui::parse_amount(&to_give) else {
+                    ui::outln!("{}", ui::msg!(not_a_number));
+                    continue;
+                };
+                let to_give = to_give.max(0);
+
+                match self.game.transfer_cash(teammate, to_give) {
+                    Err(TransferError::TooLittleCash) => {
+                        ui::outln!("{}", ui::msg!(too_little_cash, cash));
+                    }
+
+                    Err(TransferError::Bankrupt) | Ok(_) => break,
+                }
+            }
+        }
+    }
+
+    /// Trade stocks for a computer player. They don't borrow or give cash away.
+    fn computer_trade(&mut self) {
+        let trade_companies: Vec<usize> = (0..self.game.get_companies().len())
+            .filter(|&i| self.game.get_company(i).in_use)
+            .collect();
+
+        for i in trade_companies {
+            let to_buy = self.game.computer_trade();
+
+            // If the trade can't be made after all, e.g. the bank is out of shares, the computer
+            // passes instead.
+            let events = match self.game.trade(i, to_buy) {
+                Ok(events) => {
+                    if to_buy != 0 {
+                        ui::outln!(
+                            "{}",
+                            ui::msg!(
+                                computer_trade,
+                                self.get_current_player_name(),
+                                to_buy,
+                                ui::company_name(i)
+                            )
+                        );
+                    }
+                    events
+                }
+                Err(_) => self.game.trade(i, 0).expect("passing on a trade failed"),
+            };
+
+            self.handle_events(events);
+        }
+    }
+
     /// Trade stocks.
     pub(crate) fn trade(&mut self) {
+        if self.is_computer(self.game.get_current_player_index()) {
+            self.computer_trade();
+            return;
+        }
+
+        self.borrow();
+        self.transfer();
+
         // Get a list of the company numbers that are available to trade.
         let trade_companies: Vec<usize> = self
             .game
@@ -25,11 +174,18 @@ impl UserInterface {
             let holdings = player.get_holdings(i);
             let cash = player.get_cash();
 
-            println!("YOUR CURRENT CASH= $ {}", cash);
+            ui::outln!("{}", ui::msg!(current_cash, cash));
 
             loop {
-                println!("BUY HOW MANY SHARES OF {} AT $ {}", co_name, share_price);
-                print!("{}YOU NOW OWN {} ", ui::tab(5), holdings);
+                ui::outln!("{}", ui::msg!(buy_prompt, co_name, share_price));
+                if self.game.options.limited_shares {
+                    ui::outln!(
+                        "{}{}",
+                        ui::tab(5),
+                        ui::msg!(shares_available, self.game.get_company(i).bank_shares)
+                    );
+                }
+                ui::out!("{}{}", ui::tab(5), ui::msg!(you_now_own, holdings));
                 let to_buy = ui::input();
 
                 if to_buy.starts_with('M') {
@@ -42,21 +198,60 @@ impl UserInterface {
                     continue;
                 }
 
-                let to_buy = to_buy.parse::<i64>().unwrap_or(0);
+                if to_buy.starts_with('L') {
+                    self.show_ledger();
+                    continue;
+                }
+
+                let Some(to_buy) = ui::parse_amount(&to_buy) else {
+                    ui::outln!("{}", ui::msg!(not_a_number));
+                    continue;
+                };
+
+                // Prices that move with trading need the player to OK the total.
+                if self.game.options.price_curve != PriceCurve::Fixed && to_buy != 0 {
+                    let quote = self.game.quote(i, to_buy);
+
+                    // The quote stops counting once it's more than the player has.
+                    if quote.total_cost > cash {
+                        ui::outln!("{}", ui::msg!(too_little_cash, cash));
+                        continue;
+                    }
+
+                    if quote.total_cost >= 0 {
+                        ui::out!("{}", ui::msg!(quote_cost, quote.total_cost));
+                    } else {
+                        ui::out!("{}", ui::msg!(quote_pay, -quote.total_cost));
+                    }
+                    ui::out!("{}", ui::msg!(quote_price, quote.new_share_price));
+
+                    if !ui::input().starts_with('Y') {
+                        continue;
+                    }
+                }
 
                 match self.game.trade(i, to_buy) {
                     Err(TradeError::TooLittleCash) => {
-                        println!("YOU ONLY HAVE $ {} - TRY AGAIN", cash);
+                        ui::outln!("{}", ui::msg!(too_little_cash, cash));
+                        continue;
+                    }
+
+                    Err(TradeError::TooFewSharesAvailable) => {
+                        // This does not exist in the original game.
+                        ui::outln!(
+                            "{}",
+                            ui::msg!(too_few_shares, self.game.get_company(i).bank_shares)
+                        );
                         continue;
                     }
 
                     Err(TradeError::TooLittleStock) => {
                         // This does not exist in the original game.
-                        println!("YOU ONLY HAVE {} SHARES - TRY AGAIN", holdings);
+                        ui::outln!("{}", ui::msg!(too_little_stock, holdings));
                         continue;
                     }
 
-                    Ok(_) => (),
+                    Ok(events) => self.handle_events(events),
                 }
 
                 break;
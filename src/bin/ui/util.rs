@@ -6,25 +6,38 @@ use crate::ui;
 /// Print the game title
 pub fn print_title() {
     ui::formfeed();
-    println!(
-        "\n\n\n{}* S * T * A * R ** L * A * N * E * S *",
-        ui::tab(10)
-    );
+    ui::outln!("\n\n\n{}{}", ui::tab(10), ui::msg!(title));
 }
 
 /// Special Announcement
 pub fn special_announcement() {
-    print!("\x07"); // bell
-    println!("{}SPECIAL ANNOUNCEMENT!!\n", ui::tab(22));
+    if ui::is_modern() {
+        let title = ui::msg!(special_announcement);
+        ui::outln!("\n{title}\n{}", "-".repeat(title.chars().count()));
+        return;
+    }
+
+    ui::out!("\x07"); // bell
+    ui::outln!("{}{}\n", ui::tab(22), ui::msg!(special_announcement));
+}
+
+/// Leave space after an announcement. The modern UI follows up with a summary instead.
+pub fn end_announcement() {
+    if !ui::is_modern() {
+        ui::outln!("\n\n\n\n");
+    }
 }
 
 impl UserInterface {
     /// Narc on wizards.
     pub(crate) fn wizard_alert(&self) {
         if self.wizard_mode() {
-            println!("\n*******************");
-            println!("*** WIZARD MODE ***");
-            println!("*******************\n");
+            let banner = ui::msg!(wizard_mode);
+            let stars = "*".repeat(banner.chars().count());
+
+            ui::outln!("\n{stars}");
+            ui::outln!("{banner}");
+            ui::outln!("{stars}\n");
         }
     }
 }
@@ -1,6 +1,6 @@
 //! A company.
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Company {
     /// True if the company is currently in play.
     pub in_use: bool,
@@ -8,6 +8,21 @@ pub struct Company {
     pub size: u64,
     /// The price-per-share of the company.
     pub share_price: u64,
+    /// Shares held by the bank that are available for players to buy. Only used when share
+    /// supply is limited in the game options.
+    pub bank_shares: i64,
+    /// Every share of the company in existence, whether the bank or the players hold it. Only
+    /// used when share supply is limited in the game options.
+    pub shares_issued: i64,
+    /// The player who holds the most stock in the company. Only tracked with the
+    /// [`MergeBonus::Majority`] rules.
+    ///
+    /// [`MergeBonus::Majority`]: crate::starlanes::MergeBonus::Majority
+    pub president: Option<usize>,
+    /// True if the company has grown large enough that it can't be absorbed in a merge.
+    pub safe: bool,
+    /// True if the company won't pay out on the next dividend, e.g. because it just merged.
+    pub dividends_suspended: bool,
 }
 
 impl Company {
@@ -17,6 +32,11 @@ impl Company {
             in_use: false,
             size: 0,
             share_price: 100,
+            bank_shares: 0,
+            shares_issued: 0,
+            president: None,
+            safe: false,
+            dividends_suspended: false,
         }
     }
 
@@ -25,6 +45,11 @@ impl Company {
         self.in_use = false;
         self.size = 0;
         self.share_price = 100;
+        self.bank_shares = 0;
+        self.shares_issued = 0;
+        self.president = None;
+        self.safe = false;
+        self.dividends_suspended = false;
     }
 }
 
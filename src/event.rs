@@ -1,12 +1,21 @@
 //! A game event.
 //!
 //! A vector of `Event`s is returned by [`make_move`], indicating the results of the player move.
+//! [`begin_turn`] also returns a vector of `Event`s for things that happen before the move, and
+//! [`pass_move`] and [`reroll`] return them for the player's other choices in the move phase.
 //!
 //! [`make_move`]: crate::starlanes::StarLanes::make_move
+//! [`pass_move`]: crate::starlanes::StarLanes::pass_move
+//! [`reroll`]: crate::starlanes::StarLanes::reroll
+//! [`begin_turn`]: crate::starlanes::StarLanes::begin_turn
 
-/// A dividend result for the current player.
+use crate::map::Point;
+
+/// A dividend result for a single player and company.
 #[derive(Debug)]
 pub struct Dividend {
+    /// The player the dividend was paid to.
+    pub player: usize,
     /// The company the dividend is from.
     pub company: usize,
     /// The amount of the dividend.
@@ -26,11 +35,42 @@ pub struct MergeInfo {
     pub bonus_paid: i64,
 }
 
+/// A forced sale of stock to cover a called loan.
+#[derive(Debug)]
+pub struct ForcedSale {
+    /// The company whose stock was sold.
+    pub company: usize,
+    /// The number of shares sold.
+    pub shares: i64,
+    /// The cash raised by the sale, all of which went toward the loan.
+    pub proceeds: i64,
+}
+
+/// Why the game ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameOverReason {
+    /// The maximum number of turns has been played. This is the original game.
+    MaxTurns,
+    /// The maximum number of full rounds has been played.
+    MaxRounds,
+    /// There weren't enough legal moves left on the board.
+    NoMovesLeft,
+    /// A player reached the target net worth. The field is the player index.
+    TargetNetWorth(usize),
+    /// A company covers enough of the board. The field is the company index.
+    BoardCovered(usize),
+    /// Every company on the board is safe from being absorbed.
+    AllCompaniesSafe,
+    /// Only one player (or none) is left who isn't bankrupt.
+    Bankruptcy,
+}
+
 pub enum Event {
     /// A event representating company formation. The field is the company index number.
     CompanyFormed(usize),
 
-    /// A event representating dividends payouts for the current player.
+    /// A event representating dividends payouts. In the original game, this was only for the
+    /// current player, but it can list every shareholder depending on the dividend schedule.
     Dividends(Vec<Dividend>),
 
     /// Stock has split. Fields: company index, split factor.
@@ -39,4 +79,47 @@ pub enum Event {
     /// Companies have merged. Fields: remaining company, absorbed company, and a set of MergeInfo
     /// objects for each player.
     Merge(usize, usize, Vec<MergeInfo>),
+
+    /// Interest has been charged on a loan. Fields: player index, interest amount.
+    LoanInterest(usize, i64),
+
+    /// A player's loan has been called and stock sold to cover it. Fields: player index, and a
+    /// list of the sales made.
+    LoanCalled(usize, Vec<ForcedSale>),
+
+    /// A player has gone bankrupt and is out of the game. The field is the player index.
+    Bankrupt(usize),
+
+    /// Pirates have raided a company's lanes. Fields: company index, old share price, new share
+    /// price.
+    PirateRaid(usize, u64, u64),
+
+    /// A company is enjoying a trade boom. Fields: company index, old share price, new share
+    /// price.
+    TradeBoom(usize, u64, u64),
+
+    /// A star has gone supernova and been destroyed. Fields: location of the star, and a list of
+    /// neighboring companies that lost value.
+    Supernova(Point, Vec<usize>),
+
+    /// A company has been subsidized, paying out to all its shareholders. Fields: company index,
+    /// amount paid per share.
+    Subsidy(usize, i64),
+
+    /// A company has a new president. Fields: company index, player index.
+    NewPresident(usize, usize),
+
+    /// A company has grown large enough that it can no longer be absorbed in a merge. The field
+    /// is the company index.
+    CompanySafe(usize),
+
+    /// A player passed instead of placing a move. The field is the player index.
+    Pass(usize),
+
+    /// A player paid to throw away their candidate moves and draw new ones. Fields: player index,
+    /// fee paid.
+    Reroll(usize, i64),
+
+    /// The game is over. The field is the reason why.
+    GameOver(GameOverReason),
 }
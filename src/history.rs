@@ -0,0 +1,159 @@
+//! Game history.
+//!
+//! If [history recording] is enabled, a [`Snapshot`] of every company and player is taken at the
+//! start of the game and at the end of every turn. These can be read back with [`get_history`]
+//! or exported as CSV or JSON for plotting after the game.
+//!
+//! [history recording]: crate::starlanes::StarLanesOptions::record_history
+//! [`get_history`]: crate::starlanes::StarLanes::get_history
+
+use std::fmt::Write;
+
+/// The state of a company at a point in time.
+#[derive(Debug, Clone)]
+pub struct CompanySnapshot {
+    /// True if the company was in play.
+    pub in_use: bool,
+    /// The size of the company on the game board.
+    pub size: u64,
+    /// The price-per-share of the company.
+    pub share_price: u64,
+}
+
+/// The state of a player at a point in time.
+#[derive(Debug, Clone)]
+pub struct PlayerSnapshot {
+    /// Cash on hand.
+    pub cash: i64,
+    /// Outstanding loan principal.
+    pub loan: i64,
+    /// Holdings in each company, indexed by company number.
+    pub holdings: Vec<i64>,
+    /// Net worth: cash plus stock value, less loans.
+    pub net_worth: i64,
+}
+
+/// The state of the game at the end of a turn.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// The number of turns played so far. The snapshot at the start of the game is turn 0.
+    pub turn: usize,
+    /// The number of full rounds played so far.
+    pub round: usize,
+    /// The state of every company, indexed by company number.
+    pub companies: Vec<CompanySnapshot>,
+    /// The state of every player, indexed by player number.
+    pub players: Vec<PlayerSnapshot>,
+}
+
+/// A time series of game snapshots.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    /// The snapshots in the order they were taken.
+    pub snapshots: Vec<Snapshot>,
+}
+
+impl History {
+    /// Construct a new, empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Export the company time series as CSV, one row per company per snapshot.
+    pub fn companies_to_csv(&self) -> String {
+        let mut csv = String::from("turn,round,company,in_use,size,share_price\n");
+
+        for s in &self.snapshots {
+            for (i, c) in s.companies.iter().enumerate() {
+                _ = writeln!(
+                    csv,
+                    "{},{},{},{},{},{}",
+                    s.turn, s.round, i, c.in_use, c.size, c.share_price
+                );
+            }
+        }
+
+        csv
+    }
+
+    /// Export the player time series as CSV, one row per player per snapshot. There is a
+    /// `holdings_N` column for every company.
+    pub fn players_to_csv(&self) -> String {
+        let company_count = self.snapshots.first().map_or(0, |s| s.companies.len());
+
+        let mut csv = String::from("turn,round,player,cash,loan,net_worth");
+        for i in 0..company_count {
+            _ = write!(csv, ",holdings_{i}");
+        }
+        csv.push('\n');
+
+        for s in &self.snapshots {
+            for (i, p) in s.players.iter().enumerate() {
+                _ = write!(
+                    csv,
+                    "{},{},{},{},{},{}",
+                    s.turn, s.round, i, p.cash, p.loan, p.net_worth
+                );
+                for co_num in 0..company_count {
+                    _ = write!(csv, ",{}", p.holdings.get(co_num).unwrap_or(&0));
+                }
+                csv.push('\n');
+            }
+        }
+
+        csv
+    }
+
+    /// Export the whole history as JSON: an array of snapshot objects.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[\n");
+
+        for (n, s) in self.snapshots.iter().enumerate() {
+            let companies: Vec<String> = s
+                .companies
+                .iter()
+                .map(|c| {
+                    format!(
+                        "{{\"in_use\":{},\"size\":{},\"share_price\":{}}}",
+                        c.in_use, c.size, c.share_price
+                    )
+                })
+                .collect();
+
+            let players: Vec<String> = s
+                .players
+                .iter()
+                .map(|p| {
+                    let holdings: Vec<String> = p.holdings.iter().map(|h| h.to_string()).collect();
+
+                    format!(
+                        "{{\"cash\":{},\"loan\":{},\"net_worth\":{},\"holdings\":[{}]}}",
+                        p.cash,
+                        p.loan,
+                        p.net_worth,
+                        holdings.join(",")
+                    )
+                })
+                .collect();
+
+            _ = write!(
+                json,
+                "  {{\"turn\":{},\"round\":{},\"companies\":[{}],\"players\":[{}]}}",
+                s.turn,
+                s.round,
+                companies.join(","),
+                players.join(",")
+            );
+
+            json.push_str(if n + 1 < self.snapshots.len() {
+                ",\n"
+            } else {
+                "\n"
+            });
+        }
+
+        json.push_str("]\n");
+
+        json
+    }
+}
@@ -14,6 +14,7 @@
 
 pub mod company;
 pub mod event;
+pub mod history;
 pub mod map;
 pub mod player;
 pub mod starlanes;
@@ -0,0 +1,142 @@
+//! Questions about the shapes of the lanes on the map.
+
+use super::{CellSet, Map, MapCell, Point};
+
+impl Map {
+    /// Return the cells next to any cell in the set, not counting the set itself.
+    fn around(&self, set: &CellSet) -> CellSet {
+        let mut result = CellSet::new(self.width * self.height);
+
+        for i in set.iter() {
+            let Point(r, c) = self.point(i);
+
+            for Point(nr, nc) in self.neighbors(r, c) {
+                result.insert(self.index(nr, nc));
+            }
+        }
+
+        result.remove_all(set);
+
+        result
+    }
+
+    /// Return the cells around a company, or an empty set if it has no cells.
+    fn around_company(&self, co_num: usize) -> CellSet {
+        match self.companies.get(co_num) {
+            Some(cells) => self.around(cells),
+            None => CellSet::new(self.width * self.height),
+        }
+    }
+
+    /// Returns the groups of a company's cells that are connected to each other by lanes. Each
+    /// group is in row-major order, and the groups are in order of their first cells.
+    ///
+    /// In a normal game, a company is always in one piece.
+    pub fn company_components(&self, co_num: usize) -> Vec<Vec<Point>> {
+        let Some(cells) = self.companies.get(co_num) else {
+            return Vec::new();
+        };
+
+        let mut remaining = cells.clone();
+        let mut components: Vec<Vec<Point>> = Vec::new();
+
+        while let Some(start) = remaining.first() {
+            let mut component: Vec<usize> = vec![start];
+            let mut to_visit: Vec<usize> = vec![start];
+
+            remaining.remove(start);
+
+            while let Some(i) = to_visit.pop() {
+                let Point(r, c) = self.point(i);
+
+                for Point(nr, nc) in self.neighbors(r, c) {
+                    let n = self.index(nr, nc);
+
+                    if remaining.contains(n) {
+                        remaining.remove(n);
+                        component.push(n);
+                        to_visit.push(n);
+                    }
+                }
+            }
+
+            component.sort_unstable();
+            components.push(component.into_iter().map(|i| self.point(i)).collect());
+        }
+
+        components
+    }
+
+    /// Returns the number of stars next to a company. Each star is counted once, however many of
+    /// the company's cells it's next to.
+    pub fn company_adjacent_stars(&self, co_num: usize) -> usize {
+        let mut stars = self.around_company(co_num);
+
+        stars.retain_all(&self.stars);
+
+        stars.len()
+    }
+
+    /// Returns the smallest box holding all of a company's cells, as its top left and bottom
+    /// right corners, or `None` if the company isn't on the map.
+    pub fn company_bounds(&self, co_num: usize) -> Option<(Point, Point)> {
+        self.company_cells(co_num)
+            .fold(None, |bounds, Point(r, c)| {
+                let Some((Point(top, left), Point(bottom, right))) = bounds else {
+                    return Some((Point(r, c), Point(r, c)));
+                };
+
+                Some((
+                    Point(top.min(r), left.min(c)),
+                    Point(bottom.max(r), right.max(c)),
+                ))
+            })
+    }
+
+    /// Returns the empty cells next to a company, where it could grow, in row-major order.
+    ///
+    /// Some of these might merge it with another company instead; see
+    /// [`merge_cells`](Self::merge_cells).
+    pub fn company_frontier(&self, co_num: usize) -> Vec<Point> {
+        let mut frontier = self.around_company(co_num);
+
+        frontier.remove_all(&self.occupied);
+
+        frontier.iter().map(|i| self.point(i)).collect()
+    }
+
+    /// Returns the empty cells that would merge companies if played, in row-major order, with the
+    /// companies that would merge. The companies are in the order the game considers them when
+    /// breaking ties: north, south, east, west.
+    ///
+    /// This doesn't take into account whether the move is allowed, e.g. if both companies are
+    /// safe.
+    pub fn merge_cells(&self) -> Vec<(Point, Vec<usize>)> {
+        let mut all_companies = CellSet::new(self.width * self.height);
+
+        for cells in &self.companies {
+            all_companies.add_all(cells);
+        }
+
+        let mut candidates = self.around(&all_companies);
+        candidates.remove_all(&self.occupied);
+
+        candidates
+            .iter()
+            .filter_map(|i| {
+                let Point(r, c) = self.point(i);
+                let mut companies: Vec<usize> = Vec::new();
+
+                for Point(nr, nc) in self.neighbors(r, c) {
+                    if let MapCell::Company(co_num) = self.get(nr, nc)
+                        && !companies.contains(&(co_num as usize))
+                    {
+                        companies.push(co_num as usize);
+                    }
+                }
+
+                (companies.len() > 1).then_some((Point(r, c), companies))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,330 @@
+//! The game map.
+//!
+//! Each [`Map`] is a grid of [`MapCell`]s. Cell 0,0 is the upper left, row zero, columm zero.
+//! Under the hood, the cells are stored as bitsets so the game can be searched and simulated
+//! quickly.
+//!
+//! [`Map`]: Map [`MapCell`]: MapCell
+
+use rand::Rng;
+
+mod geometry;
+
+/// A row, column point on the map.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Point(pub usize, pub usize);
+
+/// All the things that can appear in a map cell.
+#[derive(Debug, Eq, Hash, PartialEq, Copy, Clone)]
+pub enum MapCell {
+    // Empty space.
+    Space,
+    // An unaffiliated outpost.
+    Outpost,
+    // A star.
+    Star,
+    // A company, identified by the `u32` field.
+    Company(u32),
+}
+
+/// A set of map cells, one bit per cell, in row-major order.
+#[derive(Debug, Clone, Default)]
+struct CellSet {
+    words: Vec<u64>,
+}
+
+impl CellSet {
+    /// Construct an empty set big enough for the given number of cells.
+    fn new(cell_count: usize) -> Self {
+        CellSet {
+            words: vec![0; cell_count.div_ceil(64)],
+        }
+    }
+
+    /// Return true if the cell is in the set.
+    fn contains(&self, i: usize) -> bool {
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    /// Add a cell to the set.
+    fn insert(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    /// Remove a cell from the set.
+    fn remove(&mut self, i: usize) {
+        self.words[i / 64] &= !(1 << (i % 64));
+    }
+
+    /// Return the number of cells in the set.
+    fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Iterate over the cells in the set, in order.
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            let mut bits = word;
+
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+
+                let bit = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+
+                Some(w * 64 + bit)
+            })
+        })
+    }
+
+    /// Return the first cell in the set, if there is one.
+    fn first(&self) -> Option<usize> {
+        self.words
+            .iter()
+            .position(|&word| word != 0)
+            .map(|w| w * 64 + self.words[w].trailing_zeros() as usize)
+    }
+
+    /// Add every cell in another set to this one.
+    fn add_all(&mut self, other: &CellSet) {
+        for (word, other_word) in self.words.iter_mut().zip(&other.words) {
+            *word |= other_word;
+        }
+    }
+
+    /// Remove every cell in another set from this one.
+    fn remove_all(&mut self, other: &CellSet) {
+        for (word, other_word) in self.words.iter_mut().zip(&other.words) {
+            *word &= !other_word;
+        }
+    }
+
+    /// Keep only the cells that are also in another set.
+    fn retain_all(&mut self, other: &CellSet) {
+        for (word, other_word) in self.words.iter_mut().zip(&other.words) {
+            *word &= other_word;
+        }
+    }
+
+    /// Move every cell in another set into this one, leaving the other empty.
+    fn take_all(&mut self, other: &mut CellSet) {
+        self.add_all(other);
+        other.words.fill(0);
+    }
+}
+
+/// The map data.
+///
+/// The cells are stored as sets of bits, one set for stars, one for outposts, and one per
+/// company. A cell in none of them is empty space.
+#[derive(Debug, Clone)]
+pub struct Map {
+    /// The width of the map in cells.
+    pub width: usize,
+    /// The height of the map in cells.
+    pub height: usize,
+
+    /// The cells that aren't empty space, i.e. all the other sets together.
+    occupied: CellSet,
+
+    /// The cells with stars.
+    stars: CellSet,
+
+    /// The cells with unaffiliated outposts.
+    outposts: CellSet,
+
+    /// The cells belonging to each company, indexed by company number.
+    companies: Vec<CellSet>,
+
+    /// The probability of there being a star in a particular cell.
+    star_probability: f32,
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Width of the map in the original game.
+pub(crate) const DEFAULT_WIDTH: usize = 12;
+
+/// Height of the map in the original game.
+pub(crate) const DEFAULT_HEIGHT: usize = 9;
+
+/// Star probability in the original game.
+pub(crate) const DEFAULT_STAR_PROBABILITY: f32 = 0.05;
+
+impl Map {
+    /// Construct a new map with the original game parameters.
+    pub fn new() -> Self {
+        Self::new_with_params(DEFAULT_WIDTH, DEFAULT_HEIGHT, DEFAULT_STAR_PROBABILITY)
+    }
+
+    /// Construct a new map with custom parameters.
+    ///
+    /// The `star_probability` is the probability of any cell being a star, e.g. `0.05`.
+    pub fn new_with_params(width: usize, height: usize, star_probability: f32) -> Self {
+        Map {
+            width,
+            height,
+            occupied: CellSet::new(width * height),
+            stars: CellSet::new(width * height),
+            outposts: CellSet::new(width * height),
+            companies: Vec::new(),
+            star_probability,
+        }
+    }
+
+    /// Regenerate the map, overwriting any previous map data.
+    pub fn regenerate(&mut self) {
+        self.regenerate_with_rng(&mut rand::rng());
+    }
+
+    /// Regenerate the map using the given random number generator, overwriting any previous map
+    /// data. A seeded generator always makes the same map.
+    pub fn regenerate_with_rng(&mut self, rng: &mut impl Rng) {
+        let cell_count = self.width * self.height;
+
+        self.occupied = CellSet::new(cell_count);
+        self.stars = CellSet::new(cell_count);
+        self.outposts = CellSet::new(cell_count);
+        self.companies.clear();
+
+        for i in 0..cell_count {
+            let s: f32 = rng.random();
+
+            if s <= self.star_probability {
+                self.occupied.insert(i);
+                self.stars.insert(i);
+            }
+        }
+    }
+
+    /// Return the index of a row, column cell in the cell sets.
+    fn index(&self, r: usize, c: usize) -> usize {
+        if r >= self.height || c >= self.width {
+            panic!("map: coordinates out of range: {r},{c}");
+        }
+
+        r * self.width + c
+    }
+
+    /// Set a row, column map cell to a particular value.
+    pub fn set(&mut self, r: usize, c: usize, v: MapCell) {
+        let i = self.index(r, c);
+
+        if self.occupied.contains(i) {
+            self.stars.remove(i);
+            self.outposts.remove(i);
+            for company in &mut self.companies {
+                company.remove(i);
+            }
+        }
+
+        if v == MapCell::Space {
+            self.occupied.remove(i);
+        } else {
+            self.occupied.insert(i);
+        }
+
+        match v {
+            MapCell::Space => (),
+            MapCell::Star => self.stars.insert(i),
+            MapCell::Outpost => self.outposts.insert(i),
+            MapCell::Company(co_num) => self.company_cells_mut(co_num as usize).insert(i),
+        }
+    }
+
+    /// Get a map cell at a particular row, column.
+    pub fn get(&self, r: usize, c: usize) -> MapCell {
+        let i = self.index(r, c);
+
+        if !self.occupied.contains(i) {
+            MapCell::Space
+        } else if self.stars.contains(i) {
+            MapCell::Star
+        } else if self.outposts.contains(i) {
+            MapCell::Outpost
+        } else {
+            let co_num = self.companies.iter().position(|co| co.contains(i));
+            MapCell::Company(co_num.expect("map: occupied cell with nothing in it") as u32)
+        }
+    }
+
+    /// Returns the cell set for a company, making it if it isn't there yet.
+    fn company_cells_mut(&mut self, co_num: usize) -> &mut CellSet {
+        if co_num >= self.companies.len() {
+            let cell_count = self.width * self.height;
+            self.companies.resize(co_num + 1, CellSet::new(cell_count));
+        }
+
+        &mut self.companies[co_num]
+    }
+
+    /// Convert all companies from one type to another. Doesn't change anything other than the map.
+    pub fn convert(&mut self, conv_from: usize, conv_to: usize) {
+        if conv_from == conv_to || conv_from >= self.companies.len() {
+            return;
+        }
+
+        let mut from = std::mem::take(&mut self.companies[conv_from]);
+        self.company_cells_mut(conv_to).take_all(&mut from);
+        self.companies[conv_from] = from;
+    }
+
+    /// Returns the neighbors of a cell that are on the map, in the order north, south, east,
+    /// west.
+    pub fn neighbors(&self, r: usize, c: usize) -> impl Iterator<Item = Point> + use<> {
+        let (height, width) = (self.height, self.width);
+
+        [
+            (r > 0).then(|| Point(r - 1, c)),
+            (r + 1 < height).then_some(Point(r + 1, c)),
+            (c + 1 < width).then_some(Point(r, c + 1)),
+            (c > 0).then(|| Point(r, c - 1)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Returns all the empty space on the map, in row-major order.
+    pub fn empty_cells(&self) -> impl Iterator<Item = Point> + '_ {
+        let cell_count = self.width * self.height;
+
+        (0..cell_count)
+            .filter(|&i| !self.occupied.contains(i))
+            .map(|i| self.point(i))
+    }
+
+    /// Returns all the stars on the map, in row-major order.
+    pub fn star_cells(&self) -> impl Iterator<Item = Point> + '_ {
+        self.stars.iter().map(|i| self.point(i))
+    }
+
+    /// Returns the number of cells a company has on the map.
+    pub fn company_cell_count(&self, co_num: usize) -> usize {
+        self.companies.get(co_num).map_or(0, CellSet::len)
+    }
+
+    /// Returns the numbers of all the companies that have cells on the map, in order.
+    pub fn companies_on_map(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.companies.len()).filter(|&co_num| self.company_cell_count(co_num) > 0)
+    }
+
+    /// Returns all the cells a company has on the map, in row-major order.
+    pub fn company_cells(&self, co_num: usize) -> impl Iterator<Item = Point> + '_ {
+        self.companies
+            .get(co_num)
+            .into_iter()
+            .flat_map(CellSet::iter)
+            .map(|i| self.point(i))
+    }
+
+    /// Return the row, column point for an index into the cell sets.
+    fn point(&self, i: usize) -> Point {
+        Point(i / self.width, i % self.width)
+    }
+}
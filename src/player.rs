@@ -1,10 +1,62 @@
 //! Player information, stock holdings and cash.
 
 /// Default cash for original game
-const DEFAULT_CASH: i64 = 6000;
+pub(crate) const DEFAULT_CASH: i64 = 6000;
+
+/// Why cash or stock changed hands.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LedgerReason {
+    /// Shares bought from the bank.
+    Purchase,
+    /// Shares sold to the bank.
+    Sale,
+    /// Dividend paid on holdings.
+    Dividend,
+    /// Free shares for founding a company.
+    FounderShares,
+    /// Bonus paid for holding stock in the absorbed company. The company number is the absorbed
+    /// company.
+    MergeBonus(usize),
+    /// Absorbed stock converted to stock in the surviving company. The company number is the
+    /// absorbed company.
+    MergeConversion(usize),
+    /// Holdings multiplied in a stock split.
+    Split,
+    /// Subsidy paid on holdings by a galactic event.
+    Subsidy,
+    /// Cash borrowed (positive) or repaid (negative).
+    Loan,
+    /// Interest paid on a loan.
+    LoanInterest,
+    /// Stock sold off to pay down a called loan.
+    ForcedSale,
+    /// Cash given to (negative) or received from (positive) the given teammate.
+    Transfer(usize),
+    /// Fee paid to draw new candidate moves.
+    RerollFee,
+    /// Everything forfeit in bankruptcy.
+    Bankruptcy,
+}
+
+/// One movement of cash and/or stock in a player's ledger.
+#[derive(Debug, Clone)]
+pub struct LedgerEntry {
+    /// The turn number when this happened.
+    pub turn: usize,
+    /// Why it happened.
+    pub reason: LedgerReason,
+    /// The company whose stock was involved, if any.
+    pub company: Option<usize>,
+    /// Change in shares held.
+    pub shares: i64,
+    /// Change in cash on hand.
+    pub cash: i64,
+    /// Change in the loan owed.
+    pub loan: i64,
+}
 
 /// Player information.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Player {
     /// Cash on-hand. This is signed to potentially allow indebtedness as a game option.
     cash: i64,
@@ -17,6 +69,15 @@ pub struct Player {
 
     /// How much cash the player should start with at the beginning of the game.
     starting_cash: i64,
+
+    /// Outstanding loan principal. Only used when loans are enabled in the game options.
+    loan: i64,
+
+    /// True if the player has been declared bankrupt and is out of the game.
+    bankrupt: bool,
+
+    /// Every movement of cash and stock this game, oldest first.
+    ledger: Vec<LedgerEntry>,
 }
 
 impl Player {
@@ -31,6 +92,9 @@ impl Player {
             holdings: Vec::new(),
             cash: starting_cash,
             starting_cash,
+            loan: 0,
+            bankrupt: false,
+            ledger: Vec::new(),
         }
     }
 
@@ -38,6 +102,9 @@ impl Player {
     pub fn reset(&mut self) {
         self.holdings.clear();
         self.cash = self.starting_cash;
+        self.loan = 0;
+        self.bankrupt = false;
+        self.ledger.clear();
     }
 
     /// Return holdings in a particular company.
@@ -45,6 +112,11 @@ impl Player {
         *self.holdings.get(company_idx).unwrap_or(&0)
     }
 
+    /// Return holdings in every company, indexed by company number.
+    pub fn get_all_holdings(&self) -> &[i64] {
+        &self.holdings
+    }
+
     /// Helper function to grow the holdings vector if necessary.
     fn grow_holdings_vec(&mut self, company_idx: usize) {
         let required_size = company_idx + 1;
@@ -75,7 +147,7 @@ impl Player {
     /// Multiply player holdings by some factor.
     pub fn mul_holdings(&mut self, company_idx: usize, factor: i64) {
         self.grow_holdings_vec(company_idx);
-        self.holdings[company_idx] *= factor;
+        self.holdings[company_idx] = self.holdings[company_idx].saturating_mul(factor);
     }
 
     /// Return player cash.
@@ -93,6 +165,40 @@ impl Player {
         self.cash = self.cash.saturating_add(delta);
         self.cash
     }
+
+    /// Return the outstanding loan principal.
+    pub fn get_loan(&self) -> i64 {
+        self.loan
+    }
+
+    /// Add to the outstanding loan principal. Negative to pay it down.
+    pub fn add_loan(&mut self, delta: i64) -> i64 {
+        self.loan = self.loan.saturating_add(delta);
+        self.loan
+    }
+
+    /// Return true if the player has gone bankrupt.
+    pub fn is_bankrupt(&self) -> bool {
+        self.bankrupt
+    }
+
+    /// Declare the player bankrupt. All cash, stock, and debt is forfeit.
+    pub fn declare_bankrupt(&mut self) {
+        self.holdings.iter_mut().for_each(|h| *h = 0);
+        self.cash = 0;
+        self.loan = 0;
+        self.bankrupt = true;
+    }
+
+    /// Return the ledger of every cash and stock movement, oldest first.
+    pub fn get_ledger(&self) -> &[LedgerEntry] {
+        &self.ledger
+    }
+
+    /// Add an entry to the ledger.
+    pub fn record(&mut self, entry: LedgerEntry) {
+        self.ledger.push(entry);
+    }
 }
 
 impl Default for Player {
@@ -0,0 +1,129 @@
+use super::GameState::{ChooseSurvivor, Move, Trade};
+use super::{DEFAULT_FOUNDER_SHARES, DEFAULT_OUTPOST_PRICE_BOOST, DEFAULT_STAR_PRICE_BOOST};
+use super::{DEFAULT_GROWTH_PRICE_BOOST, DEFAULT_STOCK_SPLIT_LIMIT, StarLanes};
+use crate::map::{MapCell, Point};
+
+impl StarLanes {
+    /// Choose a move for a computer-controlled current player from the candidate moves.
+    ///
+    /// This is a simple greedy strategy: pick the move that adds the most to the value of the
+    /// player's stock, or that founds a new company, preferring moves next to stars.
+    pub fn computer_move(&self) -> Point {
+        if self.state != Move || self.candidate_moves.is_empty() {
+            panic!("computer_move: invalid state: {:#?}", self.state);
+        }
+
+        let player = &self.players[self.current_player];
+
+        let score = |&Point(r, c): &Point| -> i64 {
+            let neighbors = self.neighbor_count(r, c);
+
+            let boost = (neighbors.stars as u64 * DEFAULT_STAR_PRICE_BOOST
+                + neighbors.outposts.len() as u64 * DEFAULT_OUTPOST_PRICE_BOOST
+                + DEFAULT_GROWTH_PRICE_BOOST) as i64;
+
+            if neighbors.companies.is_empty() {
+                if neighbors.only_stars_outposts && self.companies_available() {
+                    return boost * DEFAULT_FOUNDER_SHARES;
+                }
+
+                // Outposts are worth a little for later.
+                return neighbors.stars as i64;
+            }
+
+            // Growing or merging companies the player holds stock in raises the value of that
+            // stock. Take the best of them, since the largest absorbs the rest.
+            neighbors
+                .companies
+                .iter()
+                .filter_map(|&Point(r, c)| match self.map.get(r, c) {
+                    MapCell::Company(co_num) => {
+                        Some(player.get_holdings(co_num as usize).saturating_mul(boost))
+                    }
+                    _ => None,
+                })
+                .max()
+                .unwrap_or(0)
+        };
+
+        // max_by_key() returns the last of equal maximums, and the first is as good as any.
+        *self
+            .candidate_moves
+            .iter()
+            .rev()
+            .max_by_key(|p| score(p))
+            .unwrap()
+    }
+
+    /// Choose which tied company survives a merge for a computer-controlled current player: the
+    /// one they hold the most stock in.
+    pub fn computer_survivor(&self) -> usize {
+        if !matches!(self.state, ChooseSurvivor(_)) {
+            panic!("computer_survivor: invalid state: {:#?}", self.state);
+        }
+
+        let player = &self.players[self.current_player];
+
+        self.get_survivor_choices()
+            .into_iter()
+            .rev()
+            .max_by_key(|&co_num| player.get_holdings(co_num))
+            .unwrap()
+    }
+
+    /// Choose how many shares of the company being traded a computer-controlled current player
+    /// buys. They put all their cash into the largest company and hold everything else.
+    pub fn computer_trade(&self) -> i64 {
+        let Trade(co_num) = self.state else {
+            panic!("computer_trade: invalid state: {:#?}", self.state);
+        };
+
+        let largest = (0..self.companies.len())
+            .filter(|&i| self.companies[i].in_use)
+            .rev()
+            .max_by_key(|&i| self.companies[i].size);
+
+        if largest != Some(co_num) {
+            return 0;
+        }
+
+        let cash = self.players[self.current_player].get_cash();
+        let price = self.companies[co_num].share_price.max(1) as i64;
+
+        let mut limit = (cash / price).max(0);
+
+        if self.options.limited_shares {
+            limit = limit.min(self.companies[co_num].bank_shares);
+        }
+
+        // Moving prices make every share after the first cost more, so search for the most that
+        // can be afforded without buying the price up into a split. Double up first so huge
+        // amounts are never quoted.
+        let affordable = |amount: i64| {
+            let quote = self.quote(co_num, amount);
+            quote.total_cost <= cash
+                && quote.new_share_price <= DEFAULT_STOCK_SPLIT_LIMIT.max(price as u64)
+        };
+
+        let mut most = 1;
+
+        while most < limit && affordable(most) {
+            most *= 2;
+        }
+
+        let mut most = most.min(limit);
+        let mut least = 0;
+
+        while least < most {
+            let amount = (least + most + 1) / 2;
+
+            if affordable(amount) {
+                least = amount;
+            } else {
+                most = amount - 1;
+            }
+        }
+
+        least
+    }
+}
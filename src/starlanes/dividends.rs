@@ -1,32 +1,69 @@
-use super::DEFAULT_DIVIDEND_PERCENTAGE;
 use super::StarLanes;
 use crate::event::{Dividend, Event};
+use crate::player::LedgerReason;
+
+/// When dividends are paid, and to whom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DividendSchedule {
+    /// The current player is paid dividends on their own stock every time they move. This is the
+    /// original game.
+    EveryMove,
+
+    /// Every shareholder is paid dividends at the end of each full round.
+    EachRound,
+}
 
 impl StarLanes {
-    /// Computes the dividents for the current player.
+    /// Computes and pays the dividends for the given players.
     ///
-    /// This also adds an [`Event`] describing the [`Dividend`] per company that the UI can use to
-    /// display the info. (The original game did not display anything.)
-    pub(super) fn dividends(&mut self, events: &mut Vec<Event>) {
+    /// This also adds an [`Event`] describing the [`Dividend`] per player and company that the UI
+    /// can use to display the info. (The original game did not display anything.)
+    ///
+    /// Companies with suspended dividends pay nothing, and their suspension is lifted.
+    pub(super) fn dividends(&mut self, player_nums: &[usize], events: &mut Vec<Event>) {
         let mut dividends: Vec<Dividend> = Vec::new();
-        let player = &mut self.players[self.current_player];
 
-        for (idx, c) in self.companies.iter().enumerate() {
-            if !c.in_use {
+        for &player_num in player_nums {
+            let player = &mut self.players[player_num];
+
+            if player.is_bankrupt() {
                 continue;
             }
 
-            let amount = (DEFAULT_DIVIDEND_PERCENTAGE / 100.0
-                * c.share_price as f32
-                * player.get_holdings(idx) as f32)
-                .round() as i64;
+            for (idx, c) in self.companies.iter().enumerate() {
+                if !c.in_use || c.dividends_suspended {
+                    continue;
+                }
+
+                let rate = (self.options.dividend_percentage
+                    + self.options.dividend_percentage_per_size * c.size as f32)
+                    / 100.0;
 
-            dividends.push(Dividend {
-                company: idx,
-                amount,
-            });
+                let amount =
+                    (rate * c.share_price as f32 * player.get_holdings(idx) as f32).round() as i64;
+
+                dividends.push(Dividend {
+                    player: player_num,
+                    company: idx,
+                    amount,
+                });
+
+                player.add_cash(amount);
+            }
+        }
+
+        for d in &dividends {
+            self.record(
+                d.player,
+                LedgerReason::Dividend,
+                Some(d.company),
+                0,
+                d.amount,
+            );
+        }
 
-            player.add_cash(amount);
+        for c in &mut self.companies {
+            c.dividends_suspended = false;
         }
 
         if !dividends.is_empty() {
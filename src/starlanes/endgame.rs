@@ -0,0 +1,58 @@
+use super::StarLanes;
+use crate::event::GameOverReason;
+
+impl StarLanes {
+    /// Check if the game should end at the end of a turn, and if so, why.
+    ///
+    /// The conditions are checked in order of precedence, so if a player hits the target net
+    /// worth on the last turn of the game, that's the reason given.
+    pub(super) fn check_game_over(&self, solvent_count: usize) -> Option<GameOverReason> {
+        if let Some(target) = self.options.target_net_worth {
+            // If more than one player gets there at the same time, the richest wins.
+            let winner = (0..self.players.len())
+                .filter(|&p| !self.players[p].is_bankrupt())
+                .filter(|&p| self.get_net_worth(p) >= target)
+                .max_by_key(|&p| self.get_net_worth(p));
+
+            if let Some(p) = winner {
+                return Some(GameOverReason::TargetNetWorth(p));
+            }
+        }
+
+        if let Some(percentage) = self.options.board_coverage_percentage {
+            let board_size = (self.map.width * self.map.height) as f32;
+
+            let covering = self
+                .companies
+                .iter()
+                .position(|c| c.in_use && c.size as f32 / board_size * 100.0 >= percentage);
+
+            if let Some(co_num) = covering {
+                return Some(GameOverReason::BoardCovered(co_num));
+            }
+        }
+
+        if self.options.end_when_all_safe
+            && self.active_company_count() > 0
+            && self.companies.iter().filter(|c| c.in_use).all(|c| c.safe)
+        {
+            return Some(GameOverReason::AllCompaniesSafe);
+        }
+
+        match self.options.max_rounds {
+            Some(max_rounds) if self.round_number >= max_rounds => {
+                return Some(GameOverReason::MaxRounds);
+            }
+            None if self.turn_number >= self.options.get_max_turns() => {
+                return Some(GameOverReason::MaxTurns);
+            }
+            _ => (),
+        }
+
+        if solvent_count == 0 || (solvent_count == 1 && self.options.player_count > 1) {
+            return Some(GameOverReason::Bankruptcy);
+        }
+
+        None
+    }
+}
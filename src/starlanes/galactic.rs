@@ -0,0 +1,103 @@
+use super::DEFAULT_STAR_PRICE_BOOST;
+use super::StarLanes;
+use crate::event::Event;
+use crate::map::{MapCell, Point};
+use crate::player::LedgerReason;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+impl StarLanes {
+    /// Roll for random galactic events at the start of a turn.
+    ///
+    /// Each kind of event is rolled for independently, so more than one can happen in a turn.
+    pub(super) fn galactic_events(&mut self, events: &mut Vec<Event>) {
+        if self.rng.random::<f32>() < self.options.pirate_raid_probability
+            && let Some(co_num) = self.random_company()
+        {
+            let company = &mut self.companies[co_num];
+            let old_price = company.share_price;
+            let cut = self.options.pirate_raid_percentage / 100.0 * old_price as f32;
+
+            company.share_price = old_price.saturating_sub(cut as u64).max(1);
+
+            events.push(Event::PirateRaid(co_num, old_price, company.share_price));
+        }
+
+        if self.rng.random::<f32>() < self.options.trade_boom_probability
+            && let Some(co_num) = self.random_company()
+        {
+            let company = &mut self.companies[co_num];
+            let old_price = company.share_price;
+            let boost = self.options.trade_boom_percentage / 100.0 * old_price as f32;
+
+            company.share_price = company.share_price.saturating_add(boost as u64);
+
+            events.push(Event::TradeBoom(co_num, old_price, company.share_price));
+
+            self.stock_split(co_num, events);
+        }
+
+        if self.rng.random::<f32>() < self.options.supernova_probability {
+            self.supernova(events);
+        }
+
+        if self.rng.random::<f32>() < self.options.subsidy_probability
+            && let Some(co_num) = self.random_company()
+        {
+            let per_share = self.options.subsidy_per_share;
+
+            for player_num in 0..self.players.len() {
+                let holdings = self.players[player_num].get_holdings(co_num);
+
+                if holdings > 0 {
+                    let amount = holdings.saturating_mul(per_share);
+                    self.players[player_num].add_cash(amount);
+                    self.record(player_num, LedgerReason::Subsidy, Some(co_num), 0, amount);
+                }
+            }
+
+            events.push(Event::Subsidy(co_num, per_share));
+        }
+    }
+
+    /// Choose a random in-use company, if there are any.
+    fn random_company(&mut self) -> Option<usize> {
+        let in_use: Vec<usize> = (0..self.companies.len())
+            .filter(|&i| self.companies[i].in_use)
+            .collect();
+
+        in_use.choose(&mut self.rng).copied()
+    }
+
+    /// Destroy a random star. Neighboring companies lose the value the star was giving them.
+    fn supernova(&mut self, events: &mut Vec<Event>) {
+        let stars: Vec<Point> = self.map.star_cells().collect();
+
+        let Some(&star) = stars.choose(&mut self.rng) else {
+            return;
+        };
+
+        let Point(row, col) = star;
+        self.map.set(row, col, MapCell::Space);
+
+        let mut affected: Vec<usize> = Vec::new();
+
+        for &Point(r, c) in &self.neighbor_count(row, col).companies {
+            if let MapCell::Company(n) = self.map.get(r, c)
+                && !affected.contains(&(n as usize))
+            {
+                affected.push(n as usize);
+            }
+        }
+
+        for &co_num in &affected {
+            let company = &mut self.companies[co_num];
+            company.share_price = company
+                .share_price
+                .saturating_sub(DEFAULT_STAR_PRICE_BOOST)
+                .max(1);
+        }
+
+        events.push(Event::Supernova(star, affected));
+    }
+}
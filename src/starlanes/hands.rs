@@ -0,0 +1,70 @@
+use super::StarLanes;
+use crate::map::Point;
+use rand::prelude::SliceRandom;
+
+impl StarLanes {
+    /// Returns the moves in a player's hand. This is always empty unless
+    /// [persistent hands](super::StarLanesOptions::persistent_hands) are enabled.
+    ///
+    /// A hand is private to its player: a front-end that gives each player their own screen
+    /// should only show it to them.
+    pub fn get_hand(&self, player_num: usize) -> &[Point] {
+        &self.hands[player_num]
+    }
+
+    /// Refill the current player's hand. Cells that can no longer be played are discarded, and
+    /// new ones are drawn from the cells no one else is holding until the hand is full. The
+    /// cells kept from last turn stay at the front.
+    ///
+    /// Returns false, leaving the hand alone, if there aren't enough cells left to fill it.
+    pub(super) fn refill_hand(&mut self) -> bool {
+        let mut hand: Vec<Point> = self.hands[self.current_player]
+            .iter()
+            .copied()
+            .filter(|&point| self.is_playable(point))
+            .collect();
+
+        let mut pool: Vec<Point> = self
+            .map
+            .empty_cells()
+            .filter(|point| !self.hands.iter().any(|h| h.contains(point)))
+            .filter(|&point| self.is_playable(point))
+            .collect();
+
+        pool.shuffle(&mut self.rng);
+
+        let hand_size = self.options.get_candidate_move_count();
+
+        if hand.len() + pool.len() < hand_size {
+            return false;
+        }
+
+        let needed = hand_size.saturating_sub(hand.len());
+        hand.extend(pool.into_iter().take(needed));
+
+        self.hands[self.current_player] = hand;
+
+        true
+    }
+
+    /// Returns the number of cells the current player could hold in their hand: the playable
+    /// cells that no one else is holding.
+    pub(super) fn hand_moves_available(&self) -> usize {
+        self.map
+            .empty_cells()
+            .filter(|point| {
+                !self
+                    .hands
+                    .iter()
+                    .enumerate()
+                    .any(|(p, h)| p != self.current_player && h.contains(point))
+            })
+            .filter(|&point| self.is_playable(point))
+            .count()
+    }
+
+    /// Take a played cell out of the current player's hand, if it's there.
+    pub(super) fn play_from_hand(&mut self, point: Point) {
+        self.hands[self.current_player].retain(|&p| p != point);
+    }
+}
@@ -0,0 +1,259 @@
+use super::GameState::*;
+use super::StarLanes;
+use crate::event::{Event, ForcedSale};
+use crate::player::LedgerReason;
+
+/// Loan Error. This happens when trying to borrow or repay invalid amounts.
+#[derive(Debug)]
+pub enum LoanError {
+    /// Borrowing this much would exceed the player's credit limit.
+    OverCreditLimit,
+    /// Player doesn't have enough cash to repay that much.
+    TooLittleCash,
+    /// Player is trying to repay more than they owe.
+    OverRepayment,
+}
+
+impl std::fmt::Display for LoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoanError::OverCreditLimit => write!(f, "Over credit limit"),
+            LoanError::TooLittleCash => write!(f, "Not enough cash"),
+            LoanError::OverRepayment => write!(f, "Repayment exceeds loan"),
+        }
+    }
+}
+
+impl std::error::Error for LoanError {}
+
+impl StarLanes {
+    /// Returns how much more a player can borrow before hitting the margin limit.
+    pub fn get_credit_limit(&self, player_num: usize) -> i64 {
+        let max_loan = (self.options.loan_margin_percentage / 100.0
+            * self.get_stock_value(player_num) as f32) as i64;
+
+        max_loan
+            .saturating_sub(self.players[player_num].get_loan())
+            .max(0)
+    }
+
+    /// Borrow (positive `amount`) or repay (negative `amount`) cash. This can only be done during
+    /// the current player's trading phase, and only if loans are enabled.
+    pub fn borrow(&mut self, amount: i64) -> Result<(), LoanError> {
+        if !self.options.loans {
+            panic!("borrow: loans are not enabled");
+        }
+
+        if !matches!(self.state, Trade(_) | EndTurn) {
+            panic!("borrow: invalid state: {:#?}", self.state);
+        }
+
+        let credit_limit = self.get_credit_limit(self.current_player);
+        let player = &mut self.players[self.current_player];
+
+        if amount > credit_limit {
+            return Err(LoanError::OverCreditLimit);
+        }
+
+        if amount < 0 && amount.saturating_neg() > player.get_loan() {
+            return Err(LoanError::OverRepayment);
+        }
+
+        if amount < 0 && amount.saturating_neg() > player.get_cash() {
+            return Err(LoanError::TooLittleCash);
+        }
+
+        player.add_loan(amount);
+        player.add_cash(amount);
+
+        self.record_loan(
+            self.current_player,
+            LedgerReason::Loan,
+            None,
+            0,
+            amount,
+            amount,
+        );
+
+        self.check_invariants();
+
+        Ok(())
+    }
+
+    /// Charge interest on the current player's loan, then sell off stock if the loan has been
+    /// called. If there's not enough to cover the loan, the player goes bankrupt.
+    pub(super) fn service_loan(&mut self, events: &mut Vec<Event>) {
+        let player_num = self.current_player;
+        let player = &mut self.players[player_num];
+
+        if player.get_loan() <= 0 {
+            return;
+        }
+
+        let interest = (self.options.loan_interest_percentage / 100.0 * player.get_loan() as f32)
+            .round() as i64;
+
+        // Any interest that can't be paid in cash is added to the loan.
+        let shortfall = (interest - player.get_cash().max(0)).max(0);
+        player.add_cash(-(interest - shortfall));
+        player.add_loan(shortfall);
+
+        self.record_loan(
+            player_num,
+            LedgerReason::LoanInterest,
+            None,
+            0,
+            -(interest - shortfall),
+            shortfall,
+        );
+
+        events.push(Event::LoanInterest(player_num, interest));
+
+        let call_limit =
+            self.options.loan_call_percentage / 100.0 * self.get_stock_value(player_num) as f32;
+
+        if (self.players[player_num].get_loan() as f32) <= call_limit {
+            return;
+        }
+
+        // The loan has been called. Cash on hand goes toward it first.
+        let player = &mut self.players[player_num];
+        let payment = player.get_cash().clamp(0, player.get_loan());
+        player.add_cash(-payment);
+        player.add_loan(-payment);
+
+        self.record_loan(player_num, LedgerReason::Loan, None, 0, -payment, -payment);
+
+        // Then sell stock, most expensive first, until the player is back under the margin limit.
+        let mut by_price: Vec<usize> = (0..self.companies.len())
+            .filter(|&i| self.companies[i].in_use)
+            .collect();
+        by_price.sort_by_key(|&i| std::cmp::Reverse(self.companies[i].share_price));
+
+        let mut sales: Vec<ForcedSale> = Vec::new();
+
+        for co_num in by_price {
+            let price = i64::try_from(self.companies[co_num].share_price).unwrap_or(i64::MAX);
+            let shares = self.shares_to_sell(player_num, co_num);
+
+            if shares == 0 {
+                continue;
+            }
+
+            let proceeds = shares.saturating_mul(price);
+
+            let player = &mut self.players[player_num];
+            player.add_holdings_signed(co_num, -shares);
+            player.add_loan(-proceeds);
+
+            self.record_loan(
+                player_num,
+                LedgerReason::ForcedSale,
+                Some(co_num),
+                -shares,
+                0,
+                -proceeds,
+            );
+
+            if self.options.limited_shares {
+                let company = &mut self.companies[co_num];
+                company.bank_shares = company.bank_shares.saturating_add(shares);
+            }
+
+            sales.push(ForcedSale {
+                company: co_num,
+                shares,
+                proceeds,
+            });
+        }
+
+        // Any overpayment from the last share sold goes back to the player.
+        let player = &mut self.players[player_num];
+        if player.get_loan() < 0 {
+            let refund = player.get_loan().saturating_neg();
+            player.add_loan(refund);
+            player.add_cash(refund);
+
+            self.record_loan(
+                player_num,
+                LedgerReason::ForcedSale,
+                None,
+                0,
+                refund,
+                refund,
+            );
+        }
+
+        if !sales.is_empty() {
+            events.push(Event::LoanCalled(player_num, sales));
+        }
+
+        if self.players[player_num].get_loan() > 0 && self.get_stock_value(player_num) <= 0 {
+            for co_num in 0..self.companies.len() {
+                let holdings = self.players[player_num].get_holdings(co_num);
+
+                if holdings != 0 {
+                    // Forfeited stock is cancelled.
+                    if self.options.limited_shares {
+                        let company = &mut self.companies[co_num];
+                        company.shares_issued = company.shares_issued.saturating_sub(holdings);
+                    }
+
+                    self.record(
+                        player_num,
+                        LedgerReason::Bankruptcy,
+                        Some(co_num),
+                        holdings.saturating_neg(),
+                        0,
+                    );
+                }
+            }
+
+            let player = &self.players[player_num];
+            let (cash, loan) = (player.get_cash(), player.get_loan());
+            self.record_loan(
+                player_num,
+                LedgerReason::Bankruptcy,
+                None,
+                0,
+                cash.saturating_neg(),
+                loan.saturating_neg(),
+            );
+
+            self.players[player_num].declare_bankrupt();
+            self.hands[player_num].clear();
+            self.state = EndTurn;
+            events.push(Event::Bankrupt(player_num));
+        }
+    }
+
+    /// Returns how many shares of a company must be sold off to bring a player's called loan back
+    /// under the margin limit, or to pay it off, whichever takes fewer. This is never more than
+    /// they hold.
+    fn shares_to_sell(&self, player_num: usize, co_num: usize) -> i64 {
+        let player = &self.players[player_num];
+        let holdings = player.get_holdings(co_num);
+        let loan = player.get_loan();
+
+        if holdings <= 0 || loan <= 0 || self.get_credit_limit(player_num) > 0 {
+            return 0;
+        }
+
+        let price = i64::try_from(self.companies[co_num].share_price)
+            .unwrap_or(i64::MAX)
+            .max(1);
+        let payoff = (loan as u64).div_ceil(price as u64) as i64;
+
+        // Every share sold pays its price off the loan, but also takes the margin percentage of
+        // its price off what the player can borrow.
+        let margin = self.options.loan_margin_percentage as f64 / 100.0;
+        let shares = if margin < 1.0 {
+            let excess = loan as f64 - margin * self.get_stock_value(player_num) as f64;
+            (excess / (price as f64 * (1.0 - margin))).floor() as i64 + 1
+        } else {
+            payoff
+        };
+
+        shares.clamp(1, payoff).min(holdings)
+    }
+}
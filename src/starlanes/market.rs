@@ -0,0 +1,200 @@
+use super::StarLanes;
+
+/// How the share price responds to players buying and selling stock.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PriceCurve {
+    /// Trading doesn't affect the share price. This is the original game.
+    Fixed,
+
+    /// Every share bought raises the price by this many dollars, and every share sold lowers it
+    /// by the same.
+    Linear(u64),
+
+    /// Every share bought raises the price by this percentage, and every share sold lowers it by
+    /// the same.
+    Exponential(f32),
+}
+
+/// The result of a trade, computed before it's made.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeQuote {
+    /// The total cost of the trade. This is negative for sales, i.e. the player receives cash.
+    pub total_cost: i64,
+
+    /// The share price after the trade is made.
+    pub new_share_price: u64,
+}
+
+impl PriceCurve {
+    /// Return how far the price moves from the given price when one share is traded.
+    fn step(&self, price: u64) -> u64 {
+        match self {
+            PriceCurve::Fixed => 0,
+            PriceCurve::Linear(step) => *step,
+            PriceCurve::Exponential(pct) => {
+                // Always move at least a dollar so the curve doesn't get stuck on cheap stock.
+                let step = (*pct / 100.0 * price as f32).round() as u64;
+                step.max(1)
+            }
+        }
+    }
+
+    /// Return the number of shares, up to `limit`, that can be traded from the given price
+    /// before the step changes. The step only ever grows as the price rises and shrinks as it
+    /// falls, so this doubles up and then narrows down on the last share with the same step.
+    fn run_length(&self, price: u64, step: u64, limit: u64, buying: bool) -> u64 {
+        let same_step = |shares: u64| {
+            let moved = shares.saturating_mul(step);
+            let at = if buying {
+                price.saturating_add(moved)
+            } else {
+                price - moved
+            };
+            self.step(at) == step
+        };
+
+        // The first share always trades at the current step.
+        let mut least = 1;
+        let mut most = 1;
+
+        while most < limit && same_step(most) {
+            least = most + 1;
+            most = most.saturating_mul(2);
+        }
+
+        let mut most = most.min(limit);
+
+        while least < most {
+            let shares = least + (most - least).div_ceil(2);
+
+            if same_step(shares - 1) {
+                least = shares;
+            } else {
+                most = shares - 1;
+            }
+        }
+
+        least
+    }
+}
+
+impl StarLanes {
+    /// Compute what a trade in a company would cost without making it. `amount` is the number of
+    /// shares, negative to sell.
+    ///
+    /// With a [`PriceCurve::Fixed`] curve, this is just the share price times the amount.
+    /// Otherwise, each share bought is paid for at the current price and then pushes the price
+    /// up; each share sold first pushes the price down and is then sold at the new price.
+    ///
+    /// With a [`PriceCurve::Linear`] curve, buying shares and selling them straight back is a
+    /// wash, as long as the price doesn't bottom out. With a [`PriceCurve::Exponential`] curve,
+    /// each share sold takes its percentage off the higher price the buying left behind, so
+    /// selling straight back brings in less than the shares cost and leaves the price lower.
+    ///
+    /// If the current player can't afford to buy that many shares, the quote stops adding up
+    /// shares once the cost passes their cash. The `total_cost` is then more than they have, but
+    /// not the full cost, and the `new_share_price` is where the count stopped.
+    pub fn quote(&self, co_num: usize, amount: i64) -> TradeQuote {
+        let curve = self.options.price_curve;
+        let mut price = self.companies[co_num].share_price;
+
+        if curve == PriceCurve::Fixed {
+            return TradeQuote {
+                total_cost: amount.saturating_mul(Self::price_as_cost(price)),
+                new_share_price: price,
+            };
+        }
+
+        let buying = amount > 0;
+        let cash = self
+            .players
+            .get(self.current_player)
+            .map_or(i64::MAX, |p| p.get_cash());
+
+        // The price moves by a whole number of dollars per share, and that step stays the same
+        // over runs of shares, so each run is priced in one go. A linear curve is a single run.
+        let mut total_cost: i64 = 0;
+        let mut left = amount.unsigned_abs();
+
+        while left > 0 {
+            let step = curve.step(price);
+
+            // Selling from within a step of the bottom sends the price to the bottom.
+            let bottomed_out = !buying && price <= step;
+
+            if bottomed_out {
+                price = 1;
+            }
+
+            // Once the price stops moving, the rest of the shares all trade at the same price.
+            if step == 0 || bottomed_out || (buying && price == u64::MAX) {
+                let rest = i64::try_from(left)
+                    .unwrap_or(i64::MAX)
+                    .saturating_mul(Self::price_as_cost(price));
+                total_cost = if buying {
+                    total_cost.saturating_add(rest)
+                } else {
+                    total_cost.saturating_sub(rest)
+                };
+                break;
+            }
+
+            let limit = if buying {
+                left
+            } else {
+                // Don't let a run push the price down past the bottom.
+                left.min((price - 1) / step)
+            };
+
+            let run = curve.run_length(price, step, limit, buying);
+            let leg = Self::linear_quote(price, step, run, buying);
+
+            total_cost = total_cost.saturating_add(leg.total_cost);
+            price = leg.new_share_price;
+            left -= run;
+
+            // There's no need to price shares the player can't pay for.
+            if buying && total_cost > cash {
+                break;
+            }
+        }
+
+        TradeQuote {
+            total_cost,
+            new_share_price: price,
+        }
+    }
+
+    /// Compute a quote for a run of shares that each move the price by the same step, without
+    /// trading share by share. Costs saturate rather than overflow.
+    fn linear_quote(price: u64, step: u64, shares: u64, buying: bool) -> TradeQuote {
+        let (p, s, n) = (price as i128, step as i128, shares as i128);
+
+        let (total_cost, new_share_price) = if buying {
+            // p + (p + s) + ... + (p + (n - 1)s)
+            let total = n
+                .saturating_mul(p)
+                .saturating_add(s.saturating_mul(n * (n - 1) / 2));
+            (total, p.saturating_add(n.saturating_mul(s)))
+        } else {
+            // Shares sell at p - s, p - 2s, etc., until the price bottoms out at 1.
+            let k = if s == 0 { n } else { n.min((p - 1) / s) };
+            let total = k
+                .saturating_mul(p)
+                .saturating_sub(s.saturating_mul(k * (k + 1) / 2))
+                .saturating_add(n - k);
+            let new_price = if n <= k { p - n * s } else { 1 };
+            (-total, new_price)
+        };
+
+        TradeQuote {
+            total_cost: total_cost.clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+            new_share_price: new_share_price.min(u64::MAX as i128) as u64,
+        }
+    }
+
+    /// Return a share price as an amount of cash, saturating prices too big for one.
+    fn price_as_cost(price: u64) -> i64 {
+        i64::try_from(price).unwrap_or(i64::MAX)
+    }
+}
@@ -1,63 +1,173 @@
+use super::GameState::*;
+use super::{BUG_COMPANY_SIZE, MergeBonus, MergeRule, NeighborCounts, StarLanes};
 use super::{DEFAULT_MERGE_BONUS_FACTOR, DEFAULT_MERGE_SHARE_CONVERSION};
-use super::{NeighborCounts, StarLanes};
 use crate::event::{Event, MergeInfo};
 use crate::map::{MapCell, Point};
-use crate::player::Player;
+use crate::player::{LedgerReason, Player};
+use rand::seq::IndexedRandom;
 
 impl StarLanes {
-    /// Determine the largest neighbor company.
+    /// Determine the neighbor companies tied for largest.
     ///
     /// In order for this to behave like the original game, this depends on neighbor_count()
     /// assessing neighbors in the order N, S, E, W. Yes, we could sort them, but it would be a
     /// pain in the butt. Maybe have it return a hash instead of an array?
-    fn get_largest_neighbor_company(&self, neighbors: &NeighborCounts) -> usize {
+    ///
+    /// The companies are returned in that N, S, E, W order, without duplicates.
+    fn get_largest_neighbor_companies(&self, neighbors: &NeighborCounts) -> Vec<usize> {
         let mut max_size: u64 = 0;
-        let mut max_size_co_num: usize = 0;
+        let mut largest: Vec<usize> = Vec::new();
 
         for Point(r, c) in &neighbors.companies {
             if let MapCell::Company(co_num) = self.map.get(*r, *c) {
-                let company_size = self.companies[co_num as usize].size;
+                let co_num = co_num as usize;
+                let company_size = self.companies[co_num].size;
 
                 if company_size > max_size {
                     max_size = company_size;
-                    max_size_co_num = co_num as usize;
+                    largest.clear();
+                }
+
+                if company_size == max_size && !largest.contains(&co_num) {
+                    largest.push(co_num);
                 }
             } else {
                 panic!("merge: expected a company at {},{}", *r, *c);
             }
         }
 
-        max_size_co_num
+        largest
     }
 
-    /// Compute total shares held by all players for a specific company.
-    fn total_shares_outstanding(&self, co_num: usize) -> u64 {
-        if !self.companies[co_num].in_use {
-            panic!("total_shares_outstanding: company {co_num} is not in use");
+    /// Determine the companies that could survive a merge according to the merge rules. This is
+    /// just one company unless there's a tie that the player or chance has to break.
+    pub(super) fn get_possible_survivors(&self, neighbors: &NeighborCounts) -> Vec<usize> {
+        let largest = self.get_largest_neighbor_companies(neighbors);
+
+        // No tie, nothing to decide
+        if largest.len() == 1 {
+            return largest;
         }
 
-        let mut total: u64 = 0;
+        match self.options.merge_rule {
+            MergeRule::Classic => vec![largest[0]],
 
-        for p in &self.players {
-            total += p.get_holdings(co_num) as u64;
+            MergeRule::PlayerChoice | MergeRule::Random => largest,
+
+            MergeRule::HighestPrice => {
+                // max_by_key() returns the last of equal maximums, and we want the first to match
+                // the classic order on a tie.
+                largest
+                    .iter()
+                    .rev()
+                    .max_by_key(|&&co_num| self.companies[co_num].share_price)
+                    .copied()
+                    .into_iter()
+                    .collect()
+            }
+        }
+    }
+
+    /// Determine which company survives a merge according to the merge rules. Returns `None` if
+    /// the player has to choose.
+    pub(super) fn get_merge_survivor(&mut self, neighbors: &NeighborCounts) -> Option<usize> {
+        let survivors = self.get_possible_survivors(neighbors);
+
+        if survivors.len() == 1 {
+            return Some(survivors[0]);
         }
 
-        total
+        match self.options.merge_rule {
+            MergeRule::Random => survivors.choose(&mut self.rng).copied(),
+            _ => None,
+        }
     }
 
-    /// Compute the MergeInfo struct for a given player.
-    fn get_merge_info(&self, player: &Player, smaller_co: usize) -> MergeInfo {
-        let old_stock = player.get_holdings(smaller_co);
-        // New stock is old_stock divided by conversion factor rounded to nearest integer.
-        let new_stock =
-            (old_stock + DEFAULT_MERGE_SHARE_CONVERSION / 2) / DEFAULT_MERGE_SHARE_CONVERSION;
+    /// Returns the companies the current player can choose from to survive a merge. This is only
+    /// valid when [`must_choose_survivor`](Self::must_choose_survivor) is true.
+    pub fn get_survivor_choices(&self) -> Vec<usize> {
+        let ChooseSurvivor(Point(row, col)) = self.state else {
+            panic!("get_survivor_choices: invalid state: {:#?}", self.state);
+        };
 
+        self.get_largest_neighbor_companies(&self.neighbor_count(row, col))
+    }
+
+    /// Returns true if the current player's move caused a merge with a tie for largest company,
+    /// and the player has to choose which survives with [`choose_survivor`].
+    ///
+    /// [`choose_survivor`]: Self::choose_survivor
+    pub fn must_choose_survivor(&self) -> bool {
+        matches!(self.state, ChooseSurvivor(_))
+    }
+
+    /// Choose which company survives a merge, completing the current player's move. Returns the
+    /// same kind of results as [`make_move`](Self::make_move).
+    pub fn choose_survivor(&mut self, co_num: usize) -> Vec<Event> {
+        let ChooseSurvivor(move_point) = self.state else {
+            panic!("choose_survivor: invalid state: {:#?}", self.state);
+        };
+
+        if !self.get_survivor_choices().contains(&co_num) {
+            panic!("choose_survivor: invalid choice: {co_num}");
+        }
+
+        let mut events: Vec<Event> = Vec::new();
+
+        let Point(row, col) = move_point;
+        let neighbors = self.neighbor_count(row, col);
+
+        self.merge(move_point, &neighbors, co_num, &mut events);
+        self.finish_move(&mut events);
+
+        events
+    }
+
+    /// Compute total shares held by all players for a specific company. With the overselling bug,
+    /// some of the holdings can be negative.
+    fn total_shares_outstanding(&self, co_num: usize) -> i64 {
+        if !self.companies[co_num].in_use {
+            panic!("total_shares_outstanding: company {co_num} is not in use");
+        }
+
+        self.players.iter().fold(0, |total: i64, p| {
+            total.saturating_add(p.get_holdings(co_num))
+        })
+    }
+
+    /// Convert a number of shares in an absorbed company to shares in the surviving company.
+    ///
+    /// New stock is old stock divided by conversion factor rounded to nearest integer.
+    fn convert_merge_shares(old_stock: i64) -> i64 {
+        old_stock.saturating_add(DEFAULT_MERGE_SHARE_CONVERSION / 2)
+            / DEFAULT_MERGE_SHARE_CONVERSION
+    }
+
+    /// Compute the cash bonus paid to a player whose stock in an absorbed company is proportional
+    /// to the stock they held. This is the original game.
+    fn get_proportional_bonus(&self, player: &Player, smaller_co: usize) -> i64 {
         let total_shares = self.total_shares_outstanding(smaller_co);
-        let shares_held = old_stock;
+        let shares_held = player.get_holdings(smaller_co);
         let smaller_co_price = self.companies[smaller_co].share_price;
 
-        let bonus_paid = DEFAULT_MERGE_BONUS_FACTOR * smaller_co_price as i64 * shares_held
-            / total_shares as i64;
+        // This can only happen if all the stock has been sold off, which the original game
+        // didn't allow, or oversold.
+        if total_shares <= 0 {
+            return 0;
+        }
+
+        // Huge oversold holdings can overflow an i64 before the division brings it back down.
+        let bonus =
+            DEFAULT_MERGE_BONUS_FACTOR as i128 * smaller_co_price as i128 * shares_held as i128
+                / total_shares as i128;
+
+        bonus.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    /// Compute the MergeInfo struct for a given player.
+    fn get_merge_info(&self, player: &Player, smaller_co: usize, bonus_paid: i64) -> MergeInfo {
+        let old_stock = player.get_holdings(smaller_co);
+        let new_stock = Self::convert_merge_shares(old_stock);
 
         MergeInfo {
             old_stock,
@@ -66,15 +176,33 @@ impl StarLanes {
         }
     }
 
-    /// Merge companies.
+    /// Compute the MergeInfo structs for all players when a company is absorbed, with bonuses
+    /// depending on the game rules.
+    pub(super) fn get_player_merge_info(&self, smaller_co: usize) -> Vec<MergeInfo> {
+        let bonuses: Vec<i64> = match self.options.merge_bonus {
+            MergeBonus::Proportional => self
+                .players
+                .iter()
+                .map(|p| self.get_proportional_bonus(p, smaller_co))
+                .collect(),
+            MergeBonus::Majority => self.get_majority_bonuses(smaller_co),
+        };
+
+        self.players
+            .iter()
+            .zip(bonuses)
+            .map(|(p, bonus)| self.get_merge_info(p, smaller_co, bonus))
+            .collect()
+    }
+
+    /// Merge companies into the given surviving company.
     pub(super) fn merge(
         &mut self,
         move_point: Point,
         neighbors: &NeighborCounts,
+        biggest_co_num: usize,
         events: &mut Vec<Event>,
     ) {
-        let biggest_co_num = self.get_largest_neighbor_company(neighbors);
-
         // Loop through all possible mergee companies. This assumes the companies are in N, S, E, W
         // order in the NeighborCounts struct in order to match the original game.
         for Point(cr, cc) in &neighbors.companies {
@@ -90,17 +218,37 @@ impl StarLanes {
                 continue; // This is the merger, not a mergee.
             }
 
-            // Get merge info for all players
-            let player_merge_info: Vec<_> = self
-                .players
-                .iter()
-                .map(|p| self.get_merge_info(p, company))
-                .collect();
+            let player_merge_info = self.get_player_merge_info(company);
+            let mut converted: i64 = 0;
 
             // Run through all the players computing and adding their bonuses.
-            for (p, mi) in self.players.iter_mut().zip(player_merge_info) {
+            for (player_num, mi) in player_merge_info.into_iter().enumerate() {
+                let p = &mut self.players[player_num];
                 p.add_holdings_signed(biggest_co_num, mi.new_stock);
                 p.add_cash(mi.bonus_paid);
+                converted = converted.saturating_add(mi.new_stock);
+
+                self.record(
+                    player_num,
+                    LedgerReason::MergeConversion(company),
+                    Some(company),
+                    mi.old_stock.saturating_neg(),
+                    0,
+                );
+                self.record(
+                    player_num,
+                    LedgerReason::MergeConversion(company),
+                    Some(biggest_co_num),
+                    mi.new_stock,
+                    0,
+                );
+                self.record(
+                    player_num,
+                    LedgerReason::MergeBonus(company),
+                    Some(company),
+                    0,
+                    mi.bonus_paid,
+                );
 
                 merge_info.push(mi);
             }
@@ -111,10 +259,34 @@ impl StarLanes {
             // Convert all map spaces and mark company not in use
             self.map.convert(company, biggest_co_num);
             self.companies[company].in_use = false;
+            self.companies[company].president = None;
+            self.companies[company].safe = false;
+
+            // The bank's unsold stock converts just like the players' does. (In the classic game,
+            // this is always zero.)
+            let bank_shares = Self::convert_merge_shares(self.companies[company].bank_shares);
+            let survivor = &mut self.companies[biggest_co_num];
+            survivor.bank_shares = survivor.bank_shares.saturating_add(bank_shares);
+            self.companies[company].bank_shares = 0;
+
+            if self.options.limited_shares {
+                let survivor = &mut self.companies[biggest_co_num];
+                survivor.shares_issued = survivor
+                    .shares_issued
+                    .saturating_add(converted)
+                    .saturating_add(bank_shares);
+                self.companies[company].shares_issued = 0;
+            }
 
             // Add company sizes and prices
             self.companies[biggest_co_num].size += self.companies[company].size;
-            self.companies[biggest_co_num].share_price += self.companies[company].share_price;
+            self.companies[biggest_co_num].share_price = self.companies[biggest_co_num]
+                .share_price
+                .saturating_add(self.companies[company].share_price);
+
+            if self.options.suspend_dividends_after_merge {
+                self.companies[biggest_co_num].dividends_suspended = true;
+            }
 
             // Stock split check
             self.stock_split(biggest_co_num, events);
@@ -127,6 +299,8 @@ impl StarLanes {
 
         // The old game didn't do this, but there should be 1 more added to the company size after
         // the merge wraps up
-        //self.companies[biggest_co_num].size += 1;
+        if !BUG_COMPANY_SIZE {
+            self.companies[biggest_co_num].size += 1;
+        }
     }
 }
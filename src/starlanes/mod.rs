@@ -4,13 +4,15 @@
 //!
 //! 1. Create a new [`StarLanes`] object.
 //! 2. Call [`reset`] on that object.
-//! 3. Call [`begin_turn`].
+//! 3. Call [`begin_turn`] and handle any resulting events. Test [`game_is_over`].
 //! 4. Call [`get_moves`].
 //! 5. Test [`game_is_over`].
-//! 6. Call [`make_move`].
-//! 7. Call [`end_turn`].
-//! 8. Test [`game_is_over`].
-//! 9. `GOTO` step 3.
+//! 6. Call [`make_move`], or [`pass_move`] if passing is allowed. If the player pays to
+//!    [`reroll`] their moves instead, go back to step 4.
+//! 7. If [`must_choose_survivor`], call [`choose_survivor`].
+//! 8. Call [`end_turn`].
+//! 9. Test [`game_is_over`].
+//! 10. `GOTO` step 3.
 //!
 //! After [`get_moves`] or [`end_turn`], the UI should check if the game is over and act
 //! accordingly.
@@ -20,24 +22,63 @@
 //! [`get_moves`]: StarLanes::get_moves
 //! [`game_is_over`]: StarLanes::game_is_over
 //! [`make_move`]: StarLanes::make_move
+//! [`pass_move`]: StarLanes::pass_move
+//! [`reroll`]: StarLanes::reroll
+//! [`must_choose_survivor`]: StarLanes::must_choose_survivor
+//! [`choose_survivor`]: StarLanes::choose_survivor
 //! [`end_turn`]: StarLanes::end_turn
 
 use crate::company::Company;
-use crate::event::Event;
-use crate::map::{Map, MapCell, Point};
-use crate::player::Player;
-use rand::Rng;
+use crate::event::{Event, GameOverReason};
+use crate::history::{CompanySnapshot, History, PlayerSnapshot, Snapshot};
+use crate::map::{DEFAULT_HEIGHT, DEFAULT_STAR_PROBABILITY, DEFAULT_WIDTH, Map, MapCell, Point};
+use crate::player::{DEFAULT_CASH, LedgerEntry, LedgerReason, Player};
 use rand::prelude::SliceRandom;
-use std::collections::HashMap;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
+mod computer;
 mod dividends;
+mod endgame;
+mod galactic;
+mod hands;
+mod loans;
+mod market;
 mod merge;
+mod pass;
+mod presidency;
+mod preview;
+mod safe;
 mod split;
+mod teams;
+mod validate;
+mod view;
+
+pub use dividends::DividendSchedule;
+pub use loans::LoanError;
+pub use market::{PriceCurve, TradeQuote};
+pub use pass::RerollError;
+pub use presidency::MergeBonus;
+pub use preview::{CompanyPreview, MergePreview, MovePreview};
+pub use teams::TransferError;
+pub use validate::Violation;
+pub use view::GameView;
 
 const DEFAULT_MAX_TURNS: usize = 48;
 const DEFAULT_MAX_COMPANY_COUNT: usize = 5;
 const DEFAULT_CANDIDATE_MOVE_COUNT: usize = 5;
 
+/// The most players the game supports.
+pub const MAX_PLAYERS: usize = 8;
+/// The most companies the game supports.
+pub const MAX_COMPANIES: usize = DEFAULT_MAX_COMPANY_COUNT;
+/// The most players the original game supported. Defaults are scaled up beyond this.
+const CLASSIC_MAX_PLAYERS: usize = 4;
+/// Turns per player for games with more than the classic number of players.
+const SCALED_TURNS_PER_PLAYER: usize = 12;
+/// Candidate moves for games with more than the classic number of players.
+const SCALED_CANDIDATE_MOVE_COUNT: usize = 6;
+
 const DEFAULT_STAR_PRICE_BOOST: u64 = 500;
 const DEFAULT_GROWTH_PRICE_BOOST: u64 = 100;
 const DEFAULT_OUTPOST_PRICE_BOOST: u64 = 100;
@@ -49,6 +90,29 @@ const DEFAULT_DIVIDEND_PERCENTAGE: f32 = 5.0; // percent
 const DEFAULT_FOUNDER_SHARES: i64 = 5;
 const DEFAULT_MERGE_SHARE_CONVERSION: i64 = 2; // divisor
 const DEFAULT_MERGE_BONUS_FACTOR: i64 = 10;
+const DEFAULT_MAJORITY_BONUS_FACTOR: i64 = 10;
+const DEFAULT_MINORITY_BONUS_FACTOR: i64 = 5;
+const DEFAULT_LOAN_MARGIN_PERCENTAGE: f32 = 50.0; // percent of stock value
+const DEFAULT_LOAN_CALL_PERCENTAGE: f32 = 75.0; // percent of stock value
+const DEFAULT_LOAN_INTEREST_PERCENTAGE: f32 = 2.0; // percent per turn
+const DEFAULT_PIRATE_RAID_PROBABILITY: f32 = 0.04;
+const DEFAULT_PIRATE_RAID_PERCENTAGE: f32 = 30.0; // percent price drop
+const DEFAULT_TRADE_BOOM_PROBABILITY: f32 = 0.04;
+const DEFAULT_TRADE_BOOM_PERCENTAGE: f32 = 30.0; // percent price rise
+const DEFAULT_SUPERNOVA_PROBABILITY: f32 = 0.02;
+const DEFAULT_SUBSIDY_PROBABILITY: f32 = 0.03;
+const DEFAULT_SUBSIDY_PER_SHARE: i64 = 50;
+const DEFAULT_SHARES_ISSUED_AT_FOUNDING: i64 = 25;
+const DEFAULT_SHARES_ISSUED_PER_GROWTH: i64 = 5;
+
+// The original game didn't check for negative values on the purchase, so players could sell
+// stock they didn't have. If this is true, this game will not check, either.
+const BUG_OVERSELL: bool = true;
+
+// The original game didn't count outposts absorbed into a company, or the space that caused a
+// merge, in the company's size, so sizes can be smaller than the company's area on the map. If
+// this is true, this game will not count them, either.
+const BUG_COMPANY_SIZE: bool = true;
 
 /// Trade Error. This happens when trying to do bad trades.
 #[derive(Debug)]
@@ -57,6 +121,8 @@ pub enum TradeError {
     TooLittleCash,
     /// Player doesn't have enough stock to sell.
     TooLittleStock,
+    /// The bank doesn't have enough shares left to sell.
+    TooFewSharesAvailable,
 }
 
 impl std::fmt::Display for TradeError {
@@ -64,16 +130,18 @@ impl std::fmt::Display for TradeError {
         match self {
             TradeError::TooLittleCash => write!(f, "Not enough cash"),
             TradeError::TooLittleStock => write!(f, "Not enough stock"),
+            TradeError::TooFewSharesAvailable => write!(f, "Not enough shares available"),
         }
     }
 }
 
 impl std::error::Error for TradeError {}
 
-/// Game state representation. The game state is moved by calling various methods.
-#[derive(Debug, PartialEq)]
-enum GameState {
-    /// Before the game has begun. Game is ready for an [`StarLanes::init`] call.
+/// Game state representation. The game state is moved by calling various methods, and can be
+/// read with [`GameView::get_phase`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameState {
+    /// Before the game has begun. Game is ready for a [`StarLanes::reset`] call.
     PreInit,
 
     /// Player is beginning their turn. Game is ready for a [`StarLanes::begin_turn`] call.
@@ -83,6 +151,10 @@ enum GameState {
     /// calls.
     Move,
 
+    /// Player's move caused a merge with companies tied for largest, and they have to choose the
+    /// one that survives. Game is ready for a [`StarLanes::choose_survivor`] call.
+    ChooseSurvivor(Point),
+
     /// Player is trading in a specific company. The original game only allowed you to trade
     /// companies in order. Game is ready for [`StarLanes::trade`] call.
     Trade(usize),
@@ -101,7 +173,11 @@ enum GameState {
 use GameState::*;
 
 /// Main game structure.
-#[derive(Debug)]
+///
+/// This can be cloned, e.g. to try out moves when searching for the best one. The clone has its
+/// own copy of the random number generator, so it plays out the same way as the original given
+/// the same choices.
+#[derive(Debug, Clone)]
 pub struct StarLanes {
     /// The game map.
     pub map: Map,
@@ -109,6 +185,13 @@ pub struct StarLanes {
     /// Current game turn count. When it reaches a limit, the game is over.
     turn_number: usize,
 
+    /// Number of full rounds completed, i.e. every player has had a turn.
+    round_number: usize,
+
+    /// The player who moved first in the game. A round is over when play comes back around to
+    /// this player.
+    first_player: usize,
+
     /// Current game state.
     state: GameState,
 
@@ -127,6 +210,23 @@ pub struct StarLanes {
     /// Potential moves the current player can make this turn.
     candidate_moves: Vec<Point>,
 
+    /// Each player's hand of moves, kept from turn to turn. These are only used with persistent
+    /// hands; see [`get_hand`](Self::get_hand).
+    hands: Vec<Vec<Point>>,
+
+    /// True if the current player has already paid to reroll their moves this turn.
+    rerolled: bool,
+
+    /// Why the game ended, if it's over.
+    game_over_reason: Option<GameOverReason>,
+
+    /// Snapshots of the game taken every turn, if enabled in the options.
+    history: History,
+
+    /// Random number generator for everything in the game, seeded from the options if a seed is
+    /// given.
+    rng: StdRng,
+
     /// Various game options
     pub options: StarLanesOptions,
 }
@@ -139,16 +239,177 @@ impl Default for StarLanes {
 }
 
 /// Holds options for the game.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct StarLanesOptions {
     /// Number of players in the game.
     pub player_count: usize,
 
+    /// Seed for the random number generator. Games with the same seed and options play out the
+    /// same way given the same moves. `None` means a different game every time.
+    pub seed: Option<u64>,
+
     /// Wizard (cheating/debugging) mode.
     pub wizard_mode: bool,
 
-    /// Maximum number of turns in a game.
-    pub max_turns: usize,
+    /// Maximum number of turns in a game. This is ignored if `max_rounds` is set. `None` means
+    /// the default for the player count; see [`get_max_turns`](Self::get_max_turns).
+    pub max_turns: Option<usize>,
+
+    /// Width of the map. `None` means the default for the player count; see
+    /// [`get_map_size`](Self::get_map_size).
+    pub map_width: Option<usize>,
+
+    /// Height of the map. `None` means the default for the player count; see
+    /// [`get_map_size`](Self::get_map_size).
+    pub map_height: Option<usize>,
+
+    /// The probability of any cell on a new map being a star, e.g. `0.05`.
+    pub star_probability: f32,
+
+    /// The number of companies that can be on the board at once, up to [`MAX_COMPANIES`].
+    pub company_count: usize,
+
+    /// Cash each player starts the game with.
+    pub starting_cash: i64,
+
+    /// Number of candidate moves offered each turn. `None` means the default for the player
+    /// count; see [`get_candidate_move_count`](Self::get_candidate_move_count).
+    pub candidate_move_count: Option<usize>,
+
+    /// Each player keeps a hand of candidate moves from turn to turn, only drawing new ones to
+    /// replace the one they played and any that can no longer be played. Otherwise, the
+    /// candidates are drawn afresh every turn, as in the original game.
+    pub persistent_hands: bool,
+
+    /// Allow players to pass instead of placing a move. They still get their dividends and
+    /// trading phase.
+    pub allow_pass: bool,
+
+    /// Fee a player can pay once per turn to throw away their candidate moves and draw new ones.
+    /// `None` means no rerolls, as in the original game.
+    pub reroll_fee: Option<i64>,
+
+    /// Maximum number of full rounds in a game, where every player gets a turn each round.
+    /// `None` means the game is limited by `max_turns` instead, as in the original game.
+    pub max_rounds: Option<usize>,
+
+    /// The game ends as soon as a player's net worth reaches this amount.
+    pub target_net_worth: Option<i64>,
+
+    /// The game ends as soon as a company covers this percentage of the board.
+    pub board_coverage_percentage: Option<f32>,
+
+    /// The game ends when every company on the board is safe. This requires
+    /// `safe_company_size` to be set.
+    pub end_when_all_safe: bool,
+
+    /// Allow players to borrow cash against their stock holdings.
+    pub loans: bool,
+
+    /// How much a player can borrow, as a percentage of the market value of their stock.
+    pub loan_margin_percentage: f32,
+
+    /// If a player's loan exceeds this percentage of the market value of their stock at the start
+    /// of their turn, stock is sold off until the loan is back within the margin limit.
+    pub loan_call_percentage: f32,
+
+    /// Interest charged on the outstanding loan at the start of each of the player's turns.
+    pub loan_interest_percentage: f32,
+
+    /// Allow random galactic events to happen at the start of each turn.
+    pub random_events: bool,
+
+    /// Probability per turn of pirates raiding a company.
+    pub pirate_raid_probability: f32,
+
+    /// Percentage a company's share price drops after a pirate raid.
+    pub pirate_raid_percentage: f32,
+
+    /// Probability per turn of a trade boom for a company.
+    pub trade_boom_probability: f32,
+
+    /// Percentage a company's share price rises after a trade boom.
+    pub trade_boom_percentage: f32,
+
+    /// Probability per turn of a star going supernova and being destroyed.
+    pub supernova_probability: f32,
+
+    /// Probability per turn of a company receiving a subsidy.
+    pub subsidy_probability: f32,
+
+    /// Amount of a subsidy paid out to shareholders for every share they hold.
+    pub subsidy_per_share: i64,
+
+    /// How share prices respond to buying and selling.
+    pub price_curve: PriceCurve,
+
+    /// Limit the number of shares of each company to what the bank has issued.
+    pub limited_shares: bool,
+
+    /// Number of shares issued when a company is formed. The founder's shares come out of these.
+    pub shares_issued_at_founding: i64,
+
+    /// Number of shares issued every time a company grows.
+    pub shares_issued_per_growth: i64,
+
+    /// How bonuses are paid out to the shareholders of an absorbed company.
+    pub merge_bonus: MergeBonus,
+
+    /// With [`MergeBonus::Majority`], the largest shareholder's bonus is this times the share
+    /// price of the absorbed company.
+    pub majority_bonus_factor: i64,
+
+    /// With [`MergeBonus::Majority`], the second-largest shareholder's bonus is this times the
+    /// share price of the absorbed company.
+    pub minority_bonus_factor: i64,
+
+    /// How the surviving company is chosen when merging companies tied for largest.
+    pub merge_rule: MergeRule,
+
+    /// Companies this size or larger are safe and can't be absorbed in a merge. `None` means no
+    /// company is ever safe, as in the original game.
+    pub safe_company_size: Option<u64>,
+
+    /// When dividends are paid, and to whom.
+    pub dividend_schedule: DividendSchedule,
+
+    /// Dividends paid out, as a percentage of the share price.
+    pub dividend_percentage: f32,
+
+    /// Additional dividend percentage for every cell in the company, so larger companies pay
+    /// better dividends. This is `0.0` in the original game.
+    pub dividend_percentage_per_size: f32,
+
+    /// Suspend dividends from a company that just absorbed another for one payout.
+    pub suspend_dividends_after_merge: bool,
+
+    /// Number of players on each team, or `None` if everyone plays for themselves. Team games
+    /// need 4 to 8 players, evenly divisible into at least two teams.
+    pub team_size: Option<usize>,
+
+    /// Allow players to give cash to their teammates during their trading phase.
+    pub team_transfers: bool,
+
+    /// Record a snapshot of every company and player each turn. See
+    /// [`StarLanes::get_history`].
+    pub record_history: bool,
+}
+
+/// How to break a tie when merging companies of the same size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MergeRule {
+    /// The first company found in the order north, south, east, west survives. This is the
+    /// original game.
+    Classic,
+
+    /// The moving player chooses the survivor.
+    PlayerChoice,
+
+    /// The company with the highest share price survives. Further ties are classic order.
+    HighestPrice,
+
+    /// A random company survives.
+    Random,
 }
 
 impl StarLanesOptions {
@@ -156,12 +417,106 @@ impl StarLanesOptions {
     pub fn new() -> Self {
         Self {
             player_count: 0,
+            seed: None,
             wizard_mode: false,
-            max_turns: DEFAULT_MAX_TURNS,
+            max_turns: None,
+            map_width: None,
+            map_height: None,
+            star_probability: DEFAULT_STAR_PROBABILITY,
+            company_count: DEFAULT_MAX_COMPANY_COUNT,
+            starting_cash: DEFAULT_CASH,
+            candidate_move_count: None,
+            persistent_hands: false,
+            allow_pass: false,
+            reroll_fee: None,
+            max_rounds: None,
+            target_net_worth: None,
+            board_coverage_percentage: None,
+            end_when_all_safe: false,
+            loans: false,
+            loan_margin_percentage: DEFAULT_LOAN_MARGIN_PERCENTAGE,
+            loan_call_percentage: DEFAULT_LOAN_CALL_PERCENTAGE,
+            loan_interest_percentage: DEFAULT_LOAN_INTEREST_PERCENTAGE,
+            random_events: false,
+            pirate_raid_probability: DEFAULT_PIRATE_RAID_PROBABILITY,
+            pirate_raid_percentage: DEFAULT_PIRATE_RAID_PERCENTAGE,
+            trade_boom_probability: DEFAULT_TRADE_BOOM_PROBABILITY,
+            trade_boom_percentage: DEFAULT_TRADE_BOOM_PERCENTAGE,
+            supernova_probability: DEFAULT_SUPERNOVA_PROBABILITY,
+            subsidy_probability: DEFAULT_SUBSIDY_PROBABILITY,
+            subsidy_per_share: DEFAULT_SUBSIDY_PER_SHARE,
+            price_curve: PriceCurve::Fixed,
+            limited_shares: false,
+            shares_issued_at_founding: DEFAULT_SHARES_ISSUED_AT_FOUNDING,
+            shares_issued_per_growth: DEFAULT_SHARES_ISSUED_PER_GROWTH,
+            merge_bonus: MergeBonus::Proportional,
+            majority_bonus_factor: DEFAULT_MAJORITY_BONUS_FACTOR,
+            minority_bonus_factor: DEFAULT_MINORITY_BONUS_FACTOR,
+            merge_rule: MergeRule::Classic,
+            safe_company_size: None,
+            dividend_schedule: DividendSchedule::EveryMove,
+            dividend_percentage: DEFAULT_DIVIDEND_PERCENTAGE,
+            dividend_percentage_per_size: 0.0,
+            suspend_dividends_after_merge: false,
+            team_size: None,
+            team_transfers: false,
+            record_history: false,
         }
     }
 }
 
+impl StarLanesOptions {
+    /// Returns the player count scaled relative to the original game's maximum, or `None` if
+    /// there are few enough players that the classic defaults apply.
+    fn player_scale(&self) -> Option<f32> {
+        (self.player_count > CLASSIC_MAX_PLAYERS)
+            .then(|| self.player_count as f32 / CLASSIC_MAX_PLAYERS as f32)
+    }
+
+    /// Returns the maximum number of turns in the game.
+    ///
+    /// Unless set explicitly, this is 48 for up to four players, as in the original game, and
+    /// 12 turns per player beyond that.
+    pub fn get_max_turns(&self) -> usize {
+        self.max_turns.unwrap_or(match self.player_scale() {
+            Some(_) => SCALED_TURNS_PER_PLAYER * self.player_count,
+            None => DEFAULT_MAX_TURNS,
+        })
+    }
+
+    /// Returns the map size as (width, height).
+    ///
+    /// Unless set explicitly, this is 12x9 for up to four players, as in the original game.
+    /// Beyond that, the area grows in proportion to the number of players, keeping the same
+    /// shape.
+    pub fn get_map_size(&self) -> (usize, usize) {
+        let (width, height) = match self.player_scale() {
+            Some(scale) => (
+                (DEFAULT_WIDTH as f32 * scale.sqrt()).round() as usize,
+                (DEFAULT_HEIGHT as f32 * scale.sqrt()).round() as usize,
+            ),
+            None => (DEFAULT_WIDTH, DEFAULT_HEIGHT),
+        };
+
+        (
+            self.map_width.unwrap_or(width),
+            self.map_height.unwrap_or(height),
+        )
+    }
+
+    /// Returns the number of candidate moves offered each turn.
+    ///
+    /// Unless set explicitly, this is 5 for up to four players, as in the original game, and 6
+    /// beyond that since the map is bigger.
+    pub fn get_candidate_move_count(&self) -> usize {
+        self.candidate_move_count
+            .unwrap_or(match self.player_scale() {
+                Some(_) => SCALED_CANDIDATE_MOVE_COUNT,
+                None => DEFAULT_CANDIDATE_MOVE_COUNT,
+            })
+    }
+}
+
 impl Default for StarLanesOptions {
     /// Creates a new default set of options.
     fn default() -> Self {
@@ -182,10 +537,10 @@ pub(crate) struct NeighborCounts {
     stars: usize,
 
     /// A coordinate list of neighboring unaffiliated outposts.
-    outposts: Vec<Point>,
+    outposts: PointList,
 
     /// A coordinate list of neighboring companies.
-    companies: Vec<Point>,
+    companies: PointList,
 
     /// How many different companies are neighbors.
     discrete_companies: usize,
@@ -197,6 +552,47 @@ pub(crate) struct NeighborCounts {
     only_stars_outposts: bool,
 }
 
+/// A list of up to four points, i.e. the neighbors of a cell. This is checked for every empty
+/// cell when coming up with candidate moves, so it's kept off the heap.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PointList {
+    points: [Point; 4],
+    len: usize,
+}
+
+impl PointList {
+    /// Construct an empty list.
+    fn new() -> Self {
+        PointList {
+            points: [Point(0, 0); 4],
+            len: 0,
+        }
+    }
+
+    /// Add a point to the list.
+    fn push(&mut self, point: Point) {
+        self.points[self.len] = point;
+        self.len += 1;
+    }
+}
+
+impl std::ops::Deref for PointList {
+    type Target = [Point];
+
+    fn deref(&self) -> &[Point] {
+        &self.points[..self.len]
+    }
+}
+
+impl<'a> IntoIterator for &'a PointList {
+    type Item = &'a Point;
+    type IntoIter = std::slice::Iter<'a, Point>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
 impl StarLanes {
     /// Create a new partially-initialized game object. See [`reset`] for completing
     /// initialization.
@@ -216,45 +612,161 @@ impl StarLanes {
             state: PreInit,
             current_player: 0,
             turn_number: 0,
+            round_number: 0,
+            first_player: 0,
             players: Vec::new(),
             max_company_count: DEFAULT_MAX_COMPANY_COUNT,
             companies: Vec::new(),
             candidate_moves: Vec::new(),
+            hands: Vec::new(),
+            rerolled: false,
+            game_over_reason: None,
+            history: History::new(),
+            rng: StdRng::from_os_rng(),
             options,
         }
     }
 
     /// Reset this game object to the start of the game.
     pub fn reset(&mut self) {
-        let mut rng = rand::rng();
-
         if self.state != PreInit && self.state != GameOver {
             panic!("init: invalid state: {:#?}", self.state);
         }
 
-        self.map.regenerate();
-
-        if !(1..=4).contains(&self.options.player_count) {
+        if !(1..=MAX_PLAYERS).contains(&self.options.player_count) {
             panic!("invalid player count");
         }
 
+        if !(1..=MAX_COMPANIES).contains(&self.options.company_count) {
+            panic!("invalid company count");
+        }
+
+        if !self.teams_are_valid() {
+            panic!("invalid teams for player count");
+        }
+
+        self.rng = match self.options.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+
+        let (width, height) = self.options.get_map_size();
+        self.map = Map::new_with_params(width, height, self.options.star_probability);
+        self.map.regenerate_with_rng(&mut self.rng);
+
         self.turn_number = 0;
+        self.round_number = 0;
+        self.game_over_reason = None;
+
+        self.current_player = self.rng.random_range(0..self.options.player_count);
+        self.first_player = self.current_player;
+        self.max_company_count = self.options.company_count;
 
-        self.current_player = rng.random_range(0..self.options.player_count);
         self.players.clear();
         for _ in 0..self.options.player_count {
-            self.players.push(Player::new());
+            let mut player = Player::new_with_params(self.options.starting_cash);
+            // Start with holdings in every company so they can all be iterated over.
+            player.set_holdings(self.max_company_count - 1, 0);
+            self.players.push(player);
         }
 
+        self.hands = vec![Vec::new(); self.options.player_count];
+
         self.companies.clear();
 
-        for _ in 0..DEFAULT_MAX_COMPANY_COUNT {
+        for _ in 0..self.max_company_count {
             let mut c = Company::new();
             c.init();
             self.companies.push(c);
         }
 
+        self.history = History::new();
+        self.record_snapshot();
+
         self.state = BeginTurn;
+
+        self.check_invariants();
+    }
+
+    /// Return the game history. This is empty unless history recording is enabled in the
+    /// options.
+    pub fn get_history(&self) -> &History {
+        &self.history
+    }
+
+    /// Take a snapshot of the game for the history, if enabled.
+    fn record_snapshot(&mut self) {
+        if !self.options.record_history {
+            return;
+        }
+
+        let companies = self
+            .companies
+            .iter()
+            .map(|c| CompanySnapshot {
+                in_use: c.in_use,
+                size: c.size,
+                share_price: c.share_price,
+            })
+            .collect();
+
+        let players = (0..self.players.len())
+            .map(|p| {
+                let player = &self.players[p];
+
+                PlayerSnapshot {
+                    cash: player.get_cash(),
+                    loan: player.get_loan(),
+                    holdings: (0..self.companies.len())
+                        .map(|i| player.get_holdings(i))
+                        .collect(),
+                    net_worth: self.get_net_worth(p),
+                }
+            })
+            .collect();
+
+        self.history.snapshots.push(Snapshot {
+            turn: self.turn_number,
+            round: self.round_number,
+            companies,
+            players,
+        });
+    }
+
+    /// Record a movement of cash and/or stock in a player's ledger.
+    fn record(
+        &mut self,
+        player_num: usize,
+        reason: LedgerReason,
+        company: Option<usize>,
+        shares: i64,
+        cash: i64,
+    ) {
+        self.record_loan(player_num, reason, company, shares, cash, 0);
+    }
+
+    /// Record a movement of cash, stock and/or debt in a player's ledger.
+    fn record_loan(
+        &mut self,
+        player_num: usize,
+        reason: LedgerReason,
+        company: Option<usize>,
+        shares: i64,
+        cash: i64,
+        loan: i64,
+    ) {
+        if shares == 0 && cash == 0 && loan == 0 {
+            return;
+        }
+
+        self.players[player_num].record(LedgerEntry {
+            turn: self.turn_number,
+            reason,
+            company,
+            shares,
+            cash,
+            loan,
+        });
     }
 
     /// Returns the index of the current player.
@@ -282,15 +794,69 @@ impl StarLanes {
         &self.companies
     }
 
+    /// Returns the market value of all the stock held by a player.
+    pub fn get_stock_value(&self, player_num: usize) -> i64 {
+        let player = &self.players[player_num];
+
+        self.companies
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.in_use)
+            .map(|(i, c)| player.get_holdings(i).saturating_mul(c.share_price as i64))
+            .fold(0, i64::saturating_add)
+    }
+
+    /// Returns a player's net worth: cash plus stock value, less any outstanding loan.
+    pub fn get_net_worth(&self, player_num: usize) -> i64 {
+        let player = &self.players[player_num];
+
+        player
+            .get_cash()
+            .saturating_add(self.get_stock_value(player_num))
+            .saturating_sub(player.get_loan())
+    }
+
     /// Start the turn. This should be called from the UI.
-    pub fn begin_turn(&mut self) {
+    ///
+    /// This returns a list of [`Event`]s that happened before the player moves, e.g. random
+    /// galactic events or interest being charged on loans. In the classic game, this is always
+    /// empty.
+    ///
+    /// If the current player goes bankrupt, they don't get to move, and the UI should call
+    /// [`end_turn`](Self::end_turn) right away.
+    ///
+    /// If there aren't enough moves left on the board for the player, the game is over, and the
+    /// list ends with a [`GameOver`](Event::GameOver) event.
+    pub fn begin_turn(&mut self) -> Vec<Event> {
         if self.state != BeginTurn {
             panic!("begin_turn: invalid state: {:#?}", self.state);
         }
 
+        let mut events: Vec<Event> = Vec::new();
+
         self.candidate_moves.clear();
+        self.rerolled = false;
 
         self.state = Move;
+
+        if self.options.random_events {
+            self.galactic_events(&mut events);
+        }
+
+        if self.options.loans {
+            self.service_loan(&mut events);
+        }
+
+        self.update_presidents(&mut events);
+
+        if self.state == Move && !self.moves_available() {
+            self.game_over(GameOverReason::NoMovesLeft);
+            events.push(Event::GameOver(GameOverReason::NoMovesLeft));
+        }
+
+        self.check_invariants();
+
+        events
     }
 
     /// Assess the neighbors of a particular location on the map.
@@ -298,43 +864,32 @@ impl StarLanes {
         let mut result = NeighborCounts {
             spaces: 0,
             stars: 0,
-            outposts: Vec::new(),
-            companies: Vec::new(),
+            outposts: PointList::new(),
+            companies: PointList::new(),
             discrete_companies: 0,
             only_space: false,
             only_stars_outposts: false,
         };
 
         // To match the original game for merge tie resolution, this MUST be in the order N, S, E,
-        // W:
-        let offsets: [[i32; 2]; 4] = [[-1, 0], [1, 0], [0, 1], [0, -1]];
-
-        let mut company_count: HashMap<MapCell, usize> = HashMap::new();
-
-        for [roffset, coffset] in offsets {
-            let row = at_row as i32 + roffset;
-            let col = at_col as i32 + coffset;
-
-            if row < 0 || row >= self.map.height as i32 {
-                continue;
-            }
-
-            if col < 0 || col >= self.map.width as i32 {
-                continue;
-            }
+        // W, which is the order the map gives them in.
+        let mut seen_companies = [0; 4];
 
-            match self.map.get(row as usize, col as usize) {
+        for point @ Point(row, col) in self.map.neighbors(at_row, at_col) {
+            match self.map.get(row, col) {
                 MapCell::Space => result.spaces += 1,
                 MapCell::Star => result.stars += 1,
-                MapCell::Outpost => result.outposts.push(Point(row as usize, col as usize)),
+                MapCell::Outpost => result.outposts.push(point),
                 MapCell::Company(i) => {
-                    *company_count.entry(MapCell::Company(i)).or_insert(0) += 1;
-                    result.companies.push(Point(row as usize, col as usize));
+                    if !seen_companies[..result.discrete_companies].contains(&i) {
+                        seen_companies[result.discrete_companies] = i;
+                        result.discrete_companies += 1;
+                    }
+                    result.companies.push(point);
                 }
             }
         }
 
-        result.discrete_companies = company_count.len();
         result.only_space =
             result.stars == 0 && result.outposts.is_empty() && result.companies.is_empty();
         result.only_stars_outposts =
@@ -366,13 +921,68 @@ impl StarLanes {
         self.state == GameOver
     }
 
+    /// Return the reason the game ended, or `None` if it's still going.
+    pub fn get_game_over_reason(&self) -> Option<GameOverReason> {
+        self.game_over_reason
+    }
+
+    /// End the game for the given reason.
+    fn game_over(&mut self, reason: GameOverReason) {
+        self.state = GameOver;
+        self.game_over_reason = Some(reason);
+    }
+
+    /// Returns true if a move could legally be made at a point on the map, regardless of whether
+    /// it's one of the current candidates.
+    ///
+    /// The cell must be empty space. It can't found a new company if there are no companies
+    /// available, and it can't merge two safe companies.
+    pub fn is_playable(&self, point: Point) -> bool {
+        let Point(r, c) = point;
+
+        if self.map.get(r, c) != MapCell::Space {
+            return false;
+        }
+
+        let neighbors = self.neighbor_count(r, c);
+
+        if !self.companies_available()
+            && neighbors.companies.is_empty()
+            && (!neighbors.outposts.is_empty() || neighbors.stars > 0)
+        {
+            return false;
+        }
+
+        !self.merges_safe_companies(&neighbors)
+    }
+
+    /// Returns true if there are enough playable cells to offer the current player a full set of
+    /// candidate moves.
+    fn moves_available(&self) -> bool {
+        let needed = self.options.get_candidate_move_count();
+
+        if self.options.persistent_hands {
+            return self.hand_moves_available() >= needed;
+        }
+
+        self.map
+            .empty_cells()
+            .filter(|&point| self.is_playable(point))
+            .count()
+            >= needed
+    }
+
     /// Get the candidate moves for a particular player.
     ///
+    /// With [persistent hands](StarLanesOptions::persistent_hands), these are the player's hand,
+    /// topped up with new moves as needed.
+    ///
     /// In the standard game, it's incredibly probable that there will be enough moves available
     /// (i.e. there aren't too many filled spots to find enough valid moves).
     ///
-    /// **However**, if enough candidate moves cannot be found, the game will be over. This must be
-    /// checked by the UI via [`game_is_over`].
+    /// **However**, if enough candidate moves cannot be found, the game will be over. This is
+    /// normally caught by [`begin_turn`](Self::begin_turn), but must still be checked by the UI via
+    /// [`game_is_over`].
     ///
     /// [`game_is_over`]: Self::game_is_over
     pub fn get_moves(&mut self) -> Vec<Point> {
@@ -388,42 +998,38 @@ impl StarLanes {
             return candidates;
         }
 
-        // Loop through map getting candidate moves
-
-        for r in 0..self.map.height {
-            for c in 0..self.map.width {
-                let mapcell = self.map.get(r, c);
-
-                if mapcell != MapCell::Space {
-                    continue;
-                }
-
-                let neighbors = self.neighbor_count(r, c);
+        if self.options.persistent_hands {
+            if !self.refill_hand() {
+                self.game_over(GameOverReason::NoMovesLeft);
+                return candidates;
+            }
 
-                if !self.companies_available()
-                    && neighbors.companies.is_empty()
-                    && (!neighbors.outposts.is_empty() || neighbors.stars > 0)
-                {
-                    continue;
-                }
+            candidates.extend(self.hands[self.current_player].iter().cloned());
+            self.candidate_moves.extend(candidates.iter().cloned());
 
-                candidates.push(Point(r, c));
-            }
+            return candidates;
         }
 
-        let mut rng = rand::rng();
+        // Loop through the empty space on the map getting candidate moves
+        candidates.extend(
+            self.map
+                .empty_cells()
+                .filter(|&point| self.is_playable(point)),
+        );
 
-        candidates.shuffle(&mut rng);
+        candidates.shuffle(&mut self.rng);
 
         // Check if not enough legal moves remaining on board-- this would cause an early
         // game-over.
-        if candidates.len() < DEFAULT_CANDIDATE_MOVE_COUNT {
+        let candidate_move_count = self.options.get_candidate_move_count();
+
+        if candidates.len() < candidate_move_count {
             candidates.truncate(0);
-            self.state = GameOver;
+            self.game_over(GameOverReason::NoMovesLeft);
             return candidates;
         }
 
-        candidates.truncate(DEFAULT_CANDIDATE_MOVE_COUNT);
+        candidates.truncate(candidate_move_count);
 
         // Keep a copy for us to use later
         self.candidate_moves.extend(candidates.iter().cloned());
@@ -446,6 +1052,12 @@ impl StarLanes {
         company.size = 1;
         company.share_price = DEFAULT_GROWTH_PRICE_BOOST;
 
+        if self.options.limited_shares {
+            company.bank_shares =
+                (self.options.shares_issued_at_founding - DEFAULT_FOUNDER_SHARES).max(0);
+            company.shares_issued = company.bank_shares + DEFAULT_FOUNDER_SHARES;
+        }
+
         // Set all player holdings to 0, except the founding player.
         for (i, p) in self.players.iter_mut().enumerate() {
             // Award shares to founding player
@@ -457,6 +1069,14 @@ impl StarLanes {
             p.set_holdings(co_num, holdings);
         }
 
+        self.record(
+            self.current_player,
+            LedgerReason::FounderShares,
+            Some(co_num),
+            DEFAULT_FOUNDER_SHARES,
+            0,
+        );
+
         co_num
     }
 
@@ -466,6 +1086,15 @@ impl StarLanes {
 
         company.size += 1;
         company.share_price += DEFAULT_GROWTH_PRICE_BOOST;
+
+        if self.options.limited_shares {
+            company.bank_shares = company
+                .bank_shares
+                .saturating_add(self.options.shares_issued_per_growth);
+            company.shares_issued = company
+                .shares_issued
+                .saturating_add(self.options.shares_issued_per_growth);
+        }
     }
 
     /// Do cleanup after forming or growing a company.
@@ -488,6 +1117,9 @@ impl StarLanes {
         company.share_price += DEFAULT_STAR_PRICE_BOOST * neighbors.stars as u64;
 
         company.share_price += DEFAULT_OUTPOST_PRICE_BOOST * neighbors.outposts.len() as u64;
+        if !BUG_COMPANY_SIZE {
+            company.size += neighbors.outposts.len() as u64;
+        }
         for Point(row, col) in &neighbors.outposts {
             self.map.set(*row, *col, MapCell::Company(co_num as u32));
         }
@@ -502,6 +1134,10 @@ impl StarLanes {
     /// Called by the player to make their move at a given point. This is validated against the
     /// move list to make sure the move is valid unless [wizard mode has been set](Self::reset). If
     /// wizard mode is set, this will panic if a move is made off the map.
+    ///
+    /// If the move causes a merge where the player must choose the surviving company, the
+    /// returned list is empty and the move isn't complete until
+    /// [`choose_survivor`](Self::choose_survivor) is called.
     pub fn make_move(&mut self, move_point: Point) -> Vec<Event> {
         if self.state != Move {
             panic!("move: invalid state: {:#?}", self.state);
@@ -511,18 +1147,32 @@ impl StarLanes {
             panic!("move: invalid move: {:?}", move_point);
         }
 
+        self.play_from_hand(move_point);
+
         let mut events: Vec<Event> = Vec::new();
 
         let Point(row, col) = move_point;
 
         let neighbors = self.neighbor_count(row, col);
 
+        // Not even wizards can merge safe companies.
+        if self.merges_safe_companies(&neighbors) {
+            panic!("move: can't merge safe companies: {:?}", move_point);
+        }
+
         // println!("{:#?}", neighbors);
 
         if neighbors.only_space {
             self.map.set(row, col, MapCell::Outpost);
         } else if neighbors.discrete_companies > 1 {
-            self.merge(move_point, &neighbors, &mut events);
+            let Some(survivor) = self.get_merge_survivor(&neighbors) else {
+                // The player has to decide; the move is completed by choose_survivor().
+                self.state = ChooseSurvivor(move_point);
+                self.check_invariants();
+                return events;
+            };
+
+            self.merge(move_point, &neighbors, survivor, &mut events);
         } else if neighbors.discrete_companies == 1 {
             let Some(&Point(row, col)) = neighbors.companies.first() else {
                 panic!("expected there to be neighbor companies");
@@ -543,11 +1193,23 @@ impl StarLanes {
             events.push(Event::CompanyFormed(co_num));
         }
 
+        self.finish_move(&mut events);
+
+        events
+    }
+
+    /// Wrap up a move once the map has been updated, and move on to trading.
+    fn finish_move(&mut self, events: &mut Vec<Event>) {
+        self.update_safe_companies(events);
+        self.update_presidents(events);
+
         self.state = self.get_next_trade_state(0);
 
-        self.dividends(&mut events);
+        if self.options.dividend_schedule == DividendSchedule::EveryMove {
+            self.dividends(&[self.current_player], events);
+        }
 
-        events
+        self.check_invariants();
     }
 
     /// Return the next company Trade state from the current one. This is for the classic game
@@ -563,11 +1225,11 @@ impl StarLanes {
     }
 
     /// Trade stock in a particular company. `amount` is the number of shares, negative to sell.
-    pub fn trade(&mut self, co_num: usize, amount: i64) -> Result<(), TradeError> {
-        // The original game didn't check for negative values on the purchase. If this is true,
-        // this game will not check, either.
-        const BUG_OVERSELL: bool = true;
-
+    ///
+    /// The cost of the trade can be found ahead of time with [`quote`](Self::quote). This returns
+    /// a list of [`Event`]s caused by the trade, e.g. a stock split if buying pushes the price
+    /// high enough.
+    pub fn trade(&mut self, co_num: usize, amount: i64) -> Result<Vec<Event>, TradeError> {
         if self.state != Trade(co_num) {
             panic!(
                 "trade: invalid state for trading company {}: {:#?}",
@@ -575,6 +1237,10 @@ impl StarLanes {
             );
         }
 
+        let mut events: Vec<Event> = Vec::new();
+
+        let quote = self.quote(co_num, amount);
+
         let player = &mut self.players[self.current_player];
         let holdings = player.get_holdings(co_num);
         let cash = player.get_cash();
@@ -583,36 +1249,102 @@ impl StarLanes {
             return Err(TradeError::TooLittleStock);
         }
 
-        // Is there a safer way to do this?
-        let cost: i64 = amount * self.companies[co_num].share_price as i64;
+        if self.options.limited_shares && amount > self.companies[co_num].bank_shares {
+            return Err(TradeError::TooFewSharesAvailable);
+        }
+
+        let cost = quote.total_cost;
 
         if cost > 0 && cost > cash {
             return Err(TradeError::TooLittleCash);
         }
 
         player.add_holdings_signed(co_num, amount);
-        player.add_cash(-cost);
+        player.add_cash(cost.saturating_neg());
+
+        let reason = if amount < 0 {
+            LedgerReason::Sale
+        } else {
+            LedgerReason::Purchase
+        };
+        self.record(
+            self.current_player,
+            reason,
+            Some(co_num),
+            amount,
+            cost.saturating_neg(),
+        );
+
+        self.companies[co_num].share_price = quote.new_share_price;
+
+        if self.options.limited_shares {
+            self.companies[co_num].bank_shares =
+                self.companies[co_num].bank_shares.saturating_sub(amount);
+        }
+
+        self.stock_split(co_num, &mut events);
+        self.update_presidents(&mut events);
 
         self.state = self.get_next_trade_state(co_num + 1);
 
-        Ok(())
+        self.check_invariants();
+
+        Ok(events)
     }
 
     /// Called to wrap up the current player's turn.
-    pub fn end_turn(&mut self) {
+    ///
+    /// This returns a list of [`Event`]s that happen at the end of the turn, e.g. dividends paid
+    /// at the end of a round. If the game is over, the last of these is [`Event::GameOver`].
+    pub fn end_turn(&mut self) -> Vec<Event> {
         if !matches!(self.state, EndTurn | Trade(_) /*| FreeTrade*/) {
             panic!("end_turn: invalid state: {:#?}", self.state);
         }
 
+        let mut events: Vec<Event> = Vec::new();
+
         self.turn_number += 1;
 
-        if self.turn_number >= self.options.max_turns {
-            self.state = GameOver;
-            return;
+        let solvent_count = self.players.iter().filter(|p| !p.is_bankrupt()).count();
+
+        // Figure out who's next, skipping over anyone who is out of the game. If play comes back
+        // around to the first player, the round is over.
+        let mut next_player = self.current_player;
+        let mut round_over = false;
+
+        for _ in 0..self.options.player_count {
+            next_player = (next_player + 1) % self.options.player_count;
+
+            if next_player == self.first_player {
+                round_over = true;
+            }
+
+            if !self.players[next_player].is_bankrupt() {
+                break;
+            }
         }
 
-        self.current_player = (self.current_player + 1) % self.options.player_count;
+        if round_over {
+            self.round_number += 1;
 
-        self.state = BeginTurn;
+            if self.options.dividend_schedule == DividendSchedule::EachRound {
+                let all_players: Vec<usize> = (0..self.players.len()).collect();
+                self.dividends(&all_players, &mut events);
+            }
+        }
+
+        self.record_snapshot();
+
+        if let Some(reason) = self.check_game_over(solvent_count) {
+            self.game_over(reason);
+            events.push(Event::GameOver(reason));
+        } else {
+            self.current_player = next_player;
+            self.state = BeginTurn;
+        }
+
+        self.check_invariants();
+
+        events
     }
 }
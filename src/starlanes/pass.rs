@@ -0,0 +1,86 @@
+use super::GameState::*;
+use super::StarLanes;
+use crate::event::Event;
+use crate::player::LedgerReason;
+
+/// Reroll Error. This happens when a player can't draw new candidate moves.
+#[derive(Debug)]
+pub enum RerollError {
+    /// Player has already drawn new moves this turn.
+    AlreadyRerolled,
+    /// Player doesn't have enough cash to pay the fee.
+    TooLittleCash,
+}
+
+impl std::fmt::Display for RerollError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RerollError::AlreadyRerolled => write!(f, "Already rerolled this turn"),
+            RerollError::TooLittleCash => write!(f, "Not enough cash"),
+        }
+    }
+}
+
+impl std::error::Error for RerollError {}
+
+impl StarLanes {
+    /// Pass instead of placing a move. The player still gets their dividends and trading phase,
+    /// so this is followed by [`trade`](Self::trade) and [`end_turn`](Self::end_turn) as usual.
+    /// This can only be done during the move phase, and only if passing is enabled.
+    pub fn pass_move(&mut self) -> Vec<Event> {
+        if !self.options.allow_pass {
+            panic!("pass_move: passing is not enabled");
+        }
+
+        if self.state != Move {
+            panic!("pass_move: invalid state: {:#?}", self.state);
+        }
+
+        let mut events = vec![Event::Pass(self.current_player)];
+
+        self.finish_move(&mut events);
+
+        events
+    }
+
+    /// Pay the reroll fee to throw away the current candidate moves, or the whole hand with
+    /// persistent hands. New ones are drawn by the next call to [`get_moves`](Self::get_moves).
+    /// This can only be done once per turn, during the move phase, and only if rerolls are
+    /// enabled.
+    pub fn reroll(&mut self) -> Result<Vec<Event>, RerollError> {
+        let Some(fee) = self.options.reroll_fee else {
+            panic!("reroll: rerolls are not enabled");
+        };
+
+        if self.state != Move {
+            panic!("reroll: invalid state: {:#?}", self.state);
+        }
+
+        if self.rerolled {
+            return Err(RerollError::AlreadyRerolled);
+        }
+
+        let player_num = self.current_player;
+
+        if fee > self.players[player_num].get_cash() {
+            return Err(RerollError::TooLittleCash);
+        }
+
+        self.players[player_num].add_cash(fee.saturating_neg());
+        self.record(
+            player_num,
+            LedgerReason::RerollFee,
+            None,
+            0,
+            fee.saturating_neg(),
+        );
+
+        self.rerolled = true;
+        self.candidate_moves.clear();
+        self.hands[player_num].clear();
+
+        self.check_invariants();
+
+        Ok(vec![Event::Reroll(player_num, fee)])
+    }
+}
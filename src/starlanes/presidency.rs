@@ -0,0 +1,122 @@
+use super::StarLanes;
+use crate::event::Event;
+
+/// How bonuses are paid to the shareholders of a company that's absorbed in a merge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MergeBonus {
+    /// Every shareholder gets a bonus proportional to the percentage of the stock they held. This
+    /// is the original game.
+    Proportional,
+
+    /// The largest shareholder gets a fixed majority bonus and the second-largest gets a fixed
+    /// minority bonus. Everyone else gets nothing.
+    ///
+    /// Ties for largest split both bonuses evenly and no minority bonus is paid. Ties for second
+    /// split the minority bonus evenly. A sole shareholder gets both bonuses.
+    ///
+    /// This also tracks the president of each company, i.e. its largest shareholder.
+    Majority,
+}
+
+impl StarLanes {
+    /// Compute the majority and minority bonuses for all players for an absorbed company.
+    pub(super) fn get_majority_bonuses(&self, smaller_co: usize) -> Vec<i64> {
+        let price = self.companies[smaller_co].share_price as i64;
+        let majority_bonus = self.options.majority_bonus_factor * price;
+        let minority_bonus = self.options.minority_bonus_factor * price;
+
+        let holdings: Vec<i64> = self
+            .players
+            .iter()
+            .map(|p| p.get_holdings(smaller_co))
+            .collect();
+
+        let mut bonuses = vec![0; holdings.len()];
+
+        let largest = holdings.iter().copied().max().unwrap_or(0);
+
+        if largest <= 0 {
+            return bonuses;
+        }
+
+        let majority_holders: Vec<usize> = (0..holdings.len())
+            .filter(|&i| holdings[i] == largest)
+            .collect();
+
+        if majority_holders.len() > 1 {
+            let share = (majority_bonus + minority_bonus) / majority_holders.len() as i64;
+
+            for i in majority_holders {
+                bonuses[i] = share;
+            }
+
+            return bonuses;
+        }
+
+        bonuses[majority_holders[0]] = majority_bonus;
+
+        let second = holdings
+            .iter()
+            .copied()
+            .filter(|&h| h < largest)
+            .max()
+            .unwrap_or(0);
+
+        if second <= 0 {
+            bonuses[majority_holders[0]] += minority_bonus;
+            return bonuses;
+        }
+
+        let minority_holders: Vec<usize> = (0..holdings.len())
+            .filter(|&i| holdings[i] == second)
+            .collect();
+
+        let share = minority_bonus / minority_holders.len() as i64;
+
+        for i in minority_holders {
+            bonuses[i] = share;
+        }
+
+        bonuses
+    }
+
+    /// Update the president of every company after holdings have changed.
+    ///
+    /// The president is the largest shareholder. An incumbent keeps the title unless someone
+    /// holds strictly more stock. If there's no incumbent, ties go to the lowest player number.
+    pub(super) fn update_presidents(&mut self, events: &mut Vec<Event>) {
+        if self.options.merge_bonus != MergeBonus::Majority {
+            return;
+        }
+
+        for co_num in 0..self.companies.len() {
+            if !self.companies[co_num].in_use {
+                continue;
+            }
+
+            let incumbent = self.companies[co_num].president;
+            let mut president = incumbent;
+            let mut most = incumbent.map_or(0, |p| self.players[p].get_holdings(co_num));
+
+            for (i, p) in self.players.iter().enumerate() {
+                if p.get_holdings(co_num) > most {
+                    most = p.get_holdings(co_num);
+                    president = Some(i);
+                }
+            }
+
+            // Nobody holding any stock can't be president.
+            if most <= 0 {
+                president = None;
+            }
+
+            self.companies[co_num].president = president;
+
+            if president != incumbent
+                && let Some(p) = president
+            {
+                events.push(Event::NewPresident(co_num, p));
+            }
+        }
+    }
+}
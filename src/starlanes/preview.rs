@@ -0,0 +1,164 @@
+use super::{DEFAULT_GROWTH_PRICE_BOOST, DEFAULT_OUTPOST_PRICE_BOOST, DEFAULT_STAR_PRICE_BOOST};
+use super::{DEFAULT_STOCK_SPLIT_FACTOR, DEFAULT_STOCK_SPLIT_LIMIT, NeighborCounts, StarLanes};
+use crate::event::MergeInfo;
+use crate::map::{MapCell, Point};
+
+/// What a move would do, computed by [`StarLanes::preview_move`].
+#[derive(Debug)]
+pub enum MovePreview {
+    /// The move would place an unaffiliated outpost.
+    Outpost,
+
+    /// The move would grow an existing company.
+    Growth(CompanyPreview),
+
+    /// The move would found a new company.
+    NewCompany(CompanyPreview),
+
+    /// The move would merge companies. There's one preview per company that could survive:
+    /// just one, unless there's a tie for largest that the player or chance has to break.
+    Merge(Vec<MergePreview>),
+}
+
+/// How a company would come out of a move.
+#[derive(Debug)]
+pub struct CompanyPreview {
+    /// The company number.
+    pub company: usize,
+
+    /// The share price after the move, including any splits.
+    pub share_price: u64,
+
+    /// How many times the stock would split, multiplying everyone's holdings each time.
+    pub splits: u32,
+}
+
+/// How a merge would come out if a particular company survived it.
+#[derive(Debug)]
+pub struct MergePreview {
+    /// The surviving company.
+    pub survivor: CompanyPreview,
+
+    /// The companies that would be absorbed, in the order they'd be merged, with what each
+    /// player would get for their stock. These are the same as the [`Event::Merge`] that the
+    /// move would produce.
+    ///
+    /// [`Event::Merge`]: crate::event::Event::Merge
+    pub absorbed: Vec<(usize, Vec<MergeInfo>)>,
+}
+
+/// Return the price after any stock splits, and how many there were, the same as
+/// [`StarLanes::stock_split`] would do.
+fn split_price(mut price: u64) -> (u64, u32) {
+    let mut splits = 0;
+
+    while price > DEFAULT_STOCK_SPLIT_LIMIT {
+        price /= DEFAULT_STOCK_SPLIT_FACTOR as u64;
+        splits += 1;
+    }
+
+    (price, splits)
+}
+
+impl StarLanes {
+    /// Work out what a move would do without making it. The move has to be
+    /// [playable](Self::is_playable), but needn't be one of the current candidates.
+    ///
+    /// This only covers the move itself: it doesn't include dividends, random events, or anything
+    /// else that happens at the end of the move.
+    pub fn preview_move(&self, move_point: Point) -> MovePreview {
+        if !self.is_playable(move_point) {
+            panic!("preview_move: invalid move: {:?}", move_point);
+        }
+
+        let Point(row, col) = move_point;
+        let neighbors = self.neighbor_count(row, col);
+
+        if neighbors.only_space {
+            return MovePreview::Outpost;
+        }
+
+        if neighbors.discrete_companies > 1 {
+            return MovePreview::Merge(
+                self.get_possible_survivors(&neighbors)
+                    .into_iter()
+                    .map(|survivor| self.preview_merge(&neighbors, survivor))
+                    .collect(),
+            );
+        }
+
+        let tidy_boost = DEFAULT_STAR_PRICE_BOOST * neighbors.stars as u64
+            + DEFAULT_OUTPOST_PRICE_BOOST * neighbors.outposts.len() as u64;
+
+        if let Some(&Point(r, c)) = neighbors.companies.first() {
+            let MapCell::Company(n) = self.map.get(r, c) else {
+                panic!("expected to find a company at {},{}", r, c);
+            };
+
+            let co_num = n as usize;
+            let price = self.companies[co_num]
+                .share_price
+                .saturating_add(DEFAULT_GROWTH_PRICE_BOOST + tidy_boost);
+
+            return MovePreview::Growth(Self::preview_company(co_num, price));
+        }
+
+        let co_num = self
+            .companies
+            .iter()
+            .position(|c| !c.in_use)
+            .expect("preview_move: no companies available");
+
+        MovePreview::NewCompany(Self::preview_company(
+            co_num,
+            DEFAULT_GROWTH_PRICE_BOOST + tidy_boost,
+        ))
+    }
+
+    /// Preview a company ending up at the given price before splits.
+    fn preview_company(co_num: usize, price: u64) -> CompanyPreview {
+        let (share_price, splits) = split_price(price);
+
+        CompanyPreview {
+            company: co_num,
+            share_price,
+            splits,
+        }
+    }
+
+    /// Preview a merge into a particular survivor. This follows [`merge`](Self::merge): each
+    /// absorbed company adds its price to the survivor's, which might then split.
+    fn preview_merge(&self, neighbors: &NeighborCounts, survivor: usize) -> MergePreview {
+        let mut absorbed: Vec<(usize, Vec<MergeInfo>)> = Vec::new();
+        let mut price = self.companies[survivor].share_price;
+        let mut splits = 0;
+
+        for Point(r, c) in &neighbors.companies {
+            let MapCell::Company(n) = self.map.get(*r, *c) else {
+                panic!("preview_merge: expected to find a company at {},{}", *r, *c);
+            };
+
+            let co_num = n as usize;
+
+            if co_num == survivor || absorbed.iter().any(|(a, _)| *a == co_num) {
+                continue;
+            }
+
+            absorbed.push((co_num, self.get_player_merge_info(co_num)));
+
+            let (new_price, new_splits) =
+                split_price(price.saturating_add(self.companies[co_num].share_price));
+            price = new_price;
+            splits += new_splits;
+        }
+
+        MergePreview {
+            survivor: CompanyPreview {
+                company: survivor,
+                share_price: price,
+                splits,
+            },
+            absorbed,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::{NeighborCounts, StarLanes};
+use crate::event::Event;
+use crate::map::{MapCell, Point};
+
+impl StarLanes {
+    /// Returns true if a company is large enough to be safe from being absorbed in a merge.
+    fn is_safe_size(&self, co_num: usize) -> bool {
+        self.options
+            .safe_company_size
+            .is_some_and(|safe_size| self.companies[co_num].size >= safe_size)
+    }
+
+    /// Returns true if a move with these neighbors would merge two or more safe companies. Since
+    /// neither can be absorbed, such a move can't be made.
+    pub(super) fn merges_safe_companies(&self, neighbors: &NeighborCounts) -> bool {
+        let mut safe_companies = [0; 4];
+        let mut safe_count = 0;
+
+        for Point(r, c) in &neighbors.companies {
+            if let MapCell::Company(co_num) = self.map.get(*r, *c)
+                && self.is_safe_size(co_num as usize)
+                && !safe_companies[..safe_count].contains(&co_num)
+            {
+                safe_companies[safe_count] = co_num;
+                safe_count += 1;
+            }
+        }
+
+        safe_count > 1
+    }
+
+    /// Mark any companies that have grown large enough as safe, and announce them.
+    pub(super) fn update_safe_companies(&mut self, events: &mut Vec<Event>) {
+        for co_num in 0..self.companies.len() {
+            if self.companies[co_num].in_use
+                && !self.companies[co_num].safe
+                && self.is_safe_size(co_num)
+            {
+                self.companies[co_num].safe = true;
+                events.push(Event::CompanySafe(co_num));
+            }
+        }
+    }
+}
@@ -1,12 +1,11 @@
 use super::StarLanes;
 use super::{DEFAULT_STOCK_SPLIT_FACTOR, DEFAULT_STOCK_SPLIT_LIMIT};
 use crate::event::Event;
+use crate::player::LedgerReason;
 
 impl StarLanes {
     /// Do a stock split if necessary.
     pub(super) fn stock_split(&mut self, co_num: usize, events: &mut Vec<Event>) {
-        let company = &mut self.companies[co_num];
-
         // In the original game, the stock could only split once per move or per merge event. This
         // was mathematical; the companies had a maximum stock price of 3000 before splitting, so
         // joining two would make for a price of 6000, which would then split 2-for-1 to get back
@@ -15,13 +14,27 @@ impl StarLanes {
         // However, if we allow tuning of the parameters, it could be that the stock would split
         // repeatedly before it got below the limit. As such, this is a while loop instead of an if
         // statement.
-        while company.share_price > DEFAULT_STOCK_SPLIT_LIMIT {
+        while self.companies[co_num].share_price > DEFAULT_STOCK_SPLIT_LIMIT {
+            let company = &mut self.companies[co_num];
+
             // Price is halved
             company.share_price /= DEFAULT_STOCK_SPLIT_FACTOR as u64;
 
-            // Player's shares are doubled
-            for p in &mut self.players {
-                p.mul_holdings(co_num, DEFAULT_STOCK_SPLIT_FACTOR);
+            // The bank's shares are doubled
+            company.bank_shares = company
+                .bank_shares
+                .saturating_mul(DEFAULT_STOCK_SPLIT_FACTOR);
+            company.shares_issued = company
+                .shares_issued
+                .saturating_mul(DEFAULT_STOCK_SPLIT_FACTOR);
+
+            // And so are the players'
+            for player_num in 0..self.players.len() {
+                let holdings = self.players[player_num].get_holdings(co_num);
+                self.players[player_num].mul_holdings(co_num, DEFAULT_STOCK_SPLIT_FACTOR);
+
+                let gained = holdings.saturating_mul(DEFAULT_STOCK_SPLIT_FACTOR - 1);
+                self.record(player_num, LedgerReason::Split, Some(co_num), gained, 0);
             }
 
             // Add stock split event
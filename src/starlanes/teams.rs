@@ -0,0 +1,148 @@
+use super::GameState::*;
+use super::StarLanes;
+use crate::player::LedgerReason;
+
+/// The smallest number of players in a team game.
+const MIN_TEAM_PLAYERS: usize = 4;
+
+/// The largest number of players in a team game.
+const MAX_TEAM_PLAYERS: usize = 8;
+
+/// Transfer Error. This happens when trying to give a teammate more cash than the player has.
+#[derive(Debug)]
+pub enum TransferError {
+    /// Player doesn't have enough cash to give.
+    TooLittleCash,
+    /// The teammate is bankrupt and out of the game.
+    Bankrupt,
+}
+
+impl std::fmt::Display for TransferError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransferError::TooLittleCash => write!(f, "Not enough cash"),
+            TransferError::Bankrupt => write!(f, "Teammate is bankrupt"),
+        }
+    }
+}
+
+impl std::error::Error for TransferError {}
+
+impl StarLanes {
+    /// Returns true if the team options make sense for the player count.
+    pub(super) fn teams_are_valid(&self) -> bool {
+        Self::team_player_count_is_valid(self.options.player_count, self.options.team_size)
+    }
+
+    /// Returns true if `player_count` players can play in teams of `team_size`: there must be
+    /// at least two teams of at least two players, and 4 to 8 players in all. Any player count
+    /// is fine if `team_size` is `None`.
+    pub fn team_player_count_is_valid(player_count: usize, team_size: Option<usize>) -> bool {
+        let Some(team_size) = team_size else {
+            return true;
+        };
+
+        team_size >= 2
+            && (MIN_TEAM_PLAYERS..=MAX_TEAM_PLAYERS).contains(&player_count)
+            && player_count.is_multiple_of(team_size)
+            && player_count / team_size >= 2
+    }
+
+    /// Returns the number of teams, or 0 if this isn't a team game.
+    pub fn get_team_count(&self) -> usize {
+        self.options
+            .team_size
+            .map_or(0, |team_size| self.options.player_count / team_size)
+    }
+
+    /// Returns the team a player is on, or `None` if this isn't a team game.
+    ///
+    /// Teammates are seated apart, so that player 0 is on team 0, player 1 on team 1, and so on,
+    /// wrapping around when all the teams have a player.
+    pub fn get_team(&self, player_num: usize) -> Option<usize> {
+        self.options
+            .team_size
+            .map(|_| player_num % self.get_team_count())
+    }
+
+    /// Returns a list of the players on a team.
+    pub fn get_team_members(&self, team: usize) -> Vec<usize> {
+        (0..self.players.len())
+            .filter(|&p| self.get_team(p) == Some(team))
+            .collect()
+    }
+
+    /// Returns a list of a player's teammates, not including the player. This is empty if it
+    /// isn't a team game.
+    pub fn get_teammates(&self, player_num: usize) -> Vec<usize> {
+        match self.get_team(player_num) {
+            Some(team) => self
+                .get_team_members(team)
+                .into_iter()
+                .filter(|&p| p != player_num)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the combined net worth of a team's players.
+    pub fn get_team_net_worth(&self, team: usize) -> i64 {
+        self.get_team_members(team)
+            .into_iter()
+            .map(|p| self.get_net_worth(p))
+            .sum()
+    }
+
+    /// Give cash from the current player to a teammate. This can only be done during the current
+    /// player's trading phase, only if team transfers are enabled, and only to a teammate still in
+    /// the game.
+    pub fn transfer_cash(&mut self, to_player: usize, amount: i64) -> Result<(), TransferError> {
+        if !self.options.team_transfers {
+            panic!("transfer_cash: team transfers are not enabled");
+        }
+
+        if !matches!(self.state, Trade(_) | EndTurn) {
+            panic!("transfer_cash: invalid state: {:#?}", self.state);
+        }
+
+        if !self.get_teammates(self.current_player).contains(&to_player) {
+            panic!("transfer_cash: player {to_player} is not a teammate");
+        }
+
+        if amount < 0 {
+            panic!("transfer_cash: invalid amount: {amount}");
+        }
+
+        if self.players[to_player].is_bankrupt() {
+            return Err(TransferError::Bankrupt);
+        }
+
+        if amount > self.players[self.current_player].get_cash() {
+            return Err(TransferError::TooLittleCash);
+        }
+
+        let from_player = self.current_player;
+
+        self.players[from_player].add_cash(-amount);
+        self.players[to_player].add_cash(amount);
+
+        self.record(
+            from_player,
+            LedgerReason::Transfer(to_player),
+            None,
+            0,
+            -amount,
+        );
+        self.record(
+            to_player,
+            LedgerReason::Transfer(from_player),
+            None,
+            0,
+            amount,
+        );
+
+        self.check_invariants();
+
+        Ok(())
+    }
+}
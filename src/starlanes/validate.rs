@@ -0,0 +1,269 @@
+use super::{BUG_COMPANY_SIZE, BUG_OVERSELL, StarLanes};
+use crate::map::Point;
+
+/// Something wrong with the internal state of the game, found by
+/// [`validate`](StarLanes::validate). Any of these means there's a bug in the game.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Violation {
+    /// A map cell belongs to a company number that doesn't exist. Only the first such cell of
+    /// each company is reported.
+    UnknownCompany(Point, usize),
+    /// A map cell belongs to a company that isn't in use. Only the first such cell of each
+    /// company is reported.
+    InactiveCompany(Point, usize),
+    /// A company's size doesn't match the number of cells it has on the map.
+    WrongSize {
+        co_num: usize,
+        size: u64,
+        cells: u64,
+    },
+    /// A company is in use but has no cells on the map.
+    EmptyCompany(usize),
+    /// A company is in use but its stock is worthless.
+    ZeroSharePrice(usize),
+    /// The bank has sold more shares of a company than it has.
+    NegativeBankShares(usize, i64),
+    /// The shares of a company held by the bank and the players don't add up to the number
+    /// issued.
+    UnaccountedShares {
+        co_num: usize,
+        issued: i64,
+        held: i128,
+    },
+    /// A player's holdings don't have an entry for every company.
+    HoldingsLength {
+        player_num: usize,
+        len: usize,
+        company_count: usize,
+    },
+    /// A player holds a negative amount of stock.
+    NegativeHoldings {
+        player_num: usize,
+        co_num: usize,
+        holdings: i64,
+    },
+    /// A player has negative cash.
+    NegativeCash(usize, i64),
+    /// A player owes a negative amount.
+    NegativeLoan(usize, i64),
+    /// A bankrupt player still has cash, stock, or debt.
+    BankruptWithAssets(usize),
+    /// The current player doesn't exist.
+    InvalidCurrentPlayer(usize),
+    /// A cell is in more than one player's hand.
+    SharedHandCell(Point),
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::UnknownCompany(Point(r, c), co_num) => {
+                write!(f, "cell {r},{c} belongs to unknown company {co_num}")
+            }
+            Violation::InactiveCompany(Point(r, c), co_num) => {
+                write!(f, "cell {r},{c} belongs to inactive company {co_num}")
+            }
+            Violation::WrongSize {
+                co_num,
+                size,
+                cells,
+            } => write!(f, "company {co_num} has size {size} but {cells} cells"),
+            Violation::EmptyCompany(co_num) => {
+                write!(f, "company {co_num} is in use but has no cells")
+            }
+            Violation::ZeroSharePrice(co_num) => {
+                write!(f, "company {co_num} has a zero share price")
+            }
+            Violation::NegativeBankShares(co_num, shares) => {
+                write!(f, "company {co_num} has {shares} bank shares")
+            }
+            Violation::UnaccountedShares {
+                co_num,
+                issued,
+                held,
+            } => write!(
+                f,
+                "company {co_num} has {issued} shares issued but {held} held"
+            ),
+            Violation::HoldingsLength {
+                player_num,
+                len,
+                company_count,
+            } => write!(
+                f,
+                "player {player_num} has holdings for {len} of {company_count} companies"
+            ),
+            Violation::NegativeHoldings {
+                player_num,
+                co_num,
+                holdings,
+            } => write!(
+                f,
+                "player {player_num} holds {holdings} shares of company {co_num}"
+            ),
+            Violation::NegativeCash(player_num, cash) => {
+                write!(f, "player {player_num} has cash {cash}")
+            }
+            Violation::NegativeLoan(player_num, loan) => {
+                write!(f, "player {player_num} has a loan of {loan}")
+            }
+            Violation::BankruptWithAssets(player_num) => {
+                write!(f, "bankrupt player {player_num} still has assets or debt")
+            }
+            Violation::InvalidCurrentPlayer(player_num) => {
+                write!(f, "current player {player_num} doesn't exist")
+            }
+            Violation::SharedHandCell(Point(r, c)) => {
+                write!(f, "cell {r},{c} is in more than one hand")
+            }
+        }
+    }
+}
+
+impl StarLanes {
+    /// Check the internal consistency of the game, returning everything that's wrong. The list
+    /// is empty for a healthy game.
+    ///
+    /// Some checks are loosened when the game is emulating bugs in the original: stock holdings
+    /// and cash can go negative with the overselling bug, and company sizes can be smaller than
+    /// their area on the map with the company size bug.
+    pub fn validate(&self) -> Vec<Violation> {
+        let mut violations: Vec<Violation> = Vec::new();
+
+        // The map and the companies must agree.
+        for co_num in self.map.companies_on_map() {
+            let first_cell = || self.map.company_cells(co_num).next().unwrap();
+
+            match self.companies.get(co_num) {
+                None => violations.push(Violation::UnknownCompany(first_cell(), co_num)),
+                Some(company) if !company.in_use => {
+                    violations.push(Violation::InactiveCompany(first_cell(), co_num))
+                }
+                Some(_) => (),
+            }
+        }
+
+        for (co_num, company) in self.companies.iter().enumerate() {
+            if !company.in_use {
+                continue;
+            }
+
+            let cells = self.map.company_cell_count(co_num) as u64;
+
+            // With the company size bug, a size can fall short of the company's area, but it's
+            // never more, and never zero.
+            let size_ok = if BUG_COMPANY_SIZE {
+                (1..=cells).contains(&company.size)
+            } else {
+                company.size == cells
+            };
+
+            if cells == 0 {
+                violations.push(Violation::EmptyCompany(co_num));
+            } else if !size_ok {
+                violations.push(Violation::WrongSize {
+                    co_num,
+                    size: company.size,
+                    cells,
+                });
+            }
+
+            if company.share_price == 0 {
+                violations.push(Violation::ZeroSharePrice(co_num));
+            }
+
+            if company.bank_shares < 0 {
+                violations.push(Violation::NegativeBankShares(co_num, company.bank_shares));
+            }
+
+            // Every share is either in the bank or held by a player.
+            if self.options.limited_shares {
+                let held = self
+                    .players
+                    .iter()
+                    .map(|p| p.get_holdings(co_num) as i128)
+                    .sum::<i128>()
+                    + company.bank_shares as i128;
+
+                if held != company.shares_issued as i128 {
+                    violations.push(Violation::UnaccountedShares {
+                        co_num,
+                        issued: company.shares_issued,
+                        held,
+                    });
+                }
+            }
+        }
+
+        // Players' cash and stock must be in bounds.
+        for (player_num, player) in self.players.iter().enumerate() {
+            let holdings = player.get_all_holdings();
+
+            if holdings.len() != self.companies.len() {
+                violations.push(Violation::HoldingsLength {
+                    player_num,
+                    len: holdings.len(),
+                    company_count: self.companies.len(),
+                });
+            }
+
+            if !BUG_OVERSELL {
+                for (co_num, &h) in holdings.iter().enumerate() {
+                    if h < 0 {
+                        violations.push(Violation::NegativeHoldings {
+                            player_num,
+                            co_num,
+                            holdings: h,
+                        });
+                    }
+                }
+            }
+
+            // Negative stock pays negative dividends, so cash can go negative with it.
+            if !BUG_OVERSELL && player.get_cash() < 0 {
+                violations.push(Violation::NegativeCash(player_num, player.get_cash()));
+            }
+
+            if player.get_loan() < 0 {
+                violations.push(Violation::NegativeLoan(player_num, player.get_loan()));
+            }
+
+            if player.is_bankrupt()
+                && (player.get_cash() != 0
+                    || player.get_loan() != 0
+                    || holdings.iter().any(|&h| h != 0))
+            {
+                violations.push(Violation::BankruptWithAssets(player_num));
+            }
+        }
+
+        if !self.players.is_empty() && self.current_player >= self.players.len() {
+            violations.push(Violation::InvalidCurrentPlayer(self.current_player));
+        }
+
+        // No two players can hold the same cell.
+        for (player_num, hand) in self.hands.iter().enumerate() {
+            for &point in hand {
+                if self.hands[..player_num].iter().any(|h| h.contains(&point)) {
+                    violations.push(Violation::SharedHandCell(point));
+                }
+            }
+        }
+
+        violations
+    }
+
+    /// In debug builds, panic if the game isn't internally consistent.
+    pub(super) fn check_invariants(&self) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        let violations = self.validate();
+
+        if !violations.is_empty() {
+            let list: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+            panic!("invalid game state: {}", list.join("; "));
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use super::{GameState, StarLanes, StarLanesOptions};
+use crate::company::Company;
+use crate::map::{Map, Point};
+use crate::player::Player;
+
+/// A read-only view of a game, for AIs and front-ends that need to see everything without being
+/// able to change anything. Get one with [`StarLanes::view`].
+#[derive(Debug, Clone, Copy)]
+pub struct GameView<'a> {
+    game: &'a StarLanes,
+}
+
+impl StarLanes {
+    /// Returns a read-only view of the game.
+    pub fn view(&self) -> GameView<'_> {
+        GameView { game: self }
+    }
+}
+
+impl<'a> GameView<'a> {
+    /// Returns the number of turns that have been completed.
+    pub fn get_turn_number(&self) -> usize {
+        self.game.turn_number
+    }
+
+    /// Returns the number of full rounds that have been completed.
+    pub fn get_round_number(&self) -> usize {
+        self.game.round_number
+    }
+
+    /// Returns the number of turns left before the game hits its turn or round limit, including
+    /// the current one. The game might end sooner for other reasons, e.g. bankruptcy.
+    ///
+    /// With a round limit, this counts the turns of players who are still in the game, assuming
+    /// no one else goes bankrupt.
+    pub fn get_turns_remaining(&self) -> usize {
+        let game = self.game;
+
+        if game.state == GameState::GameOver {
+            return 0;
+        }
+
+        let Some(max_rounds) = game.options.max_rounds else {
+            return game
+                .options
+                .get_max_turns()
+                .saturating_sub(game.turn_number);
+        };
+
+        let rounds_left = max_rounds.saturating_sub(game.round_number);
+
+        if rounds_left == 0 {
+            return 0;
+        }
+
+        let player_count = game.options.player_count;
+        let solvent_count = game.players.iter().filter(|p| !p.is_bankrupt()).count();
+
+        // Count the players still to go this round, up to when play comes back to the first
+        // player.
+        let mut this_round = 0;
+        let mut player_num = game.current_player;
+
+        loop {
+            if !game.players[player_num].is_bankrupt() {
+                this_round += 1;
+            }
+
+            player_num = (player_num + 1) % player_count;
+
+            if player_num == game.first_player {
+                break;
+            }
+        }
+
+        this_round + (rounds_left - 1) * solvent_count
+    }
+
+    /// Returns the current phase of the game, i.e. what it's waiting for.
+    pub fn get_phase(&self) -> GameState {
+        self.game.state
+    }
+
+    /// Returns the current player's number.
+    pub fn get_current_player_index(&self) -> usize {
+        self.game.current_player
+    }
+
+    /// Returns the current player's candidate moves. These are only drawn once
+    /// [`StarLanes::get_moves`] has been called for the turn.
+    pub fn get_candidate_moves(&self) -> &'a [Point] {
+        &self.game.candidate_moves
+    }
+
+    /// Returns the map.
+    pub fn get_map(&self) -> &'a Map {
+        &self.game.map
+    }
+
+    /// Returns all the players.
+    pub fn get_players(&self) -> &'a [Player] {
+        &self.game.players
+    }
+
+    /// Returns all the companies, both in use and not.
+    pub fn get_companies(&self) -> &'a [Company] {
+        &self.game.companies
+    }
+
+    /// Returns the game options.
+    pub fn get_options(&self) -> &'a StarLanesOptions {
+        &self.game.options
+    }
+}
@@ -0,0 +1,462 @@
+//! Plays whole games with every choice made by a [`Chooser`], checking the engine's invariants
+//! after every action and that every move does what its preview said. This is shared by the
+//! property tests and the fuzz target.
+
+use rand::Rng;
+use rand::rngs::StdRng;
+use starlanes::event::{Event, GameOverReason};
+use starlanes::map::{MapCell, Point};
+use starlanes::starlanes::{
+    CompanyPreview, DividendSchedule, GameState, MAX_COMPANIES, MAX_PLAYERS, MergeBonus, MergeRule,
+    MovePreview, PriceCurve, RerollError, StarLanes, StarLanesOptions,
+};
+
+/// Safety net in case a game never ends.
+const MAX_TURNS: usize = 100_000;
+
+/// A source of choices for a game.
+pub trait Chooser {
+    /// Return the next raw choice.
+    fn next(&mut self) -> u64;
+
+    /// Return a number less than `n`.
+    fn pick(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// Return an amount of cash or stock, mostly small, sometimes negative, and occasionally
+    /// huge. Amounts stay well inside an `i64`, since the original game's overselling lets a
+    /// player sell stock they don't have.
+    fn amount(&mut self) -> i64 {
+        let n = self.next();
+        let m = (n >> 4) as i64;
+
+        match n % 8 {
+            0 => 0,
+            1..=3 => m % 20,
+            4 | 5 => -(m % 20),
+            6 => m % 100_000,
+            _ => m % 1_000_000_000 - 500_000_000,
+        }
+    }
+}
+
+/// Make a random set of valid options.
+pub fn random_options(rng: &mut StdRng, seed: u64) -> StarLanesOptions {
+    let mut options = StarLanesOptions::new();
+
+    options.seed = Some(seed);
+
+    options.team_size = match rng.random_range(0..4) {
+        0 => Some(2),
+        1 if rng.random() => Some(4),
+        _ => None,
+    };
+    options.player_count = match options.team_size {
+        Some(2) => 2 * rng.random_range(2..=MAX_PLAYERS / 2),
+        Some(size) => size * 2,
+        None => rng.random_range(2..=MAX_PLAYERS),
+    };
+    options.team_transfers = options.team_size.is_some() && rng.random();
+
+    if rng.random_bool(0.3) {
+        options.map_width = Some(rng.random_range(4..=26));
+        options.map_height = Some(rng.random_range(4..=20));
+    }
+    options.star_probability = rng.random_range(0.0..0.2);
+    options.company_count = rng.random_range(1..=MAX_COMPANIES);
+    options.starting_cash = rng.random_range(0..20_000);
+    if rng.random_bool(0.3) {
+        options.candidate_move_count = Some(rng.random_range(1..=8));
+    }
+    options.persistent_hands = rng.random();
+    options.allow_pass = rng.random();
+    options.reroll_fee = rng.random_bool(0.5).then(|| rng.random_range(0..2_000));
+
+    options.max_turns = rng.random_bool(0.3).then(|| rng.random_range(1..200));
+    options.max_rounds = rng.random_bool(0.2).then(|| rng.random_range(1..20));
+    options.target_net_worth = rng
+        .random_bool(0.2)
+        .then(|| rng.random_range(10_000..200_000));
+    options.board_coverage_percentage = rng.random_bool(0.2).then(|| rng.random_range(5.0..60.0));
+    options.safe_company_size = rng.random_bool(0.3).then(|| rng.random_range(2..20));
+    options.end_when_all_safe = options.safe_company_size.is_some() && rng.random();
+
+    options.loans = rng.random();
+    options.random_events = rng.random();
+    options.limited_shares = rng.random();
+    options.price_curve = match rng.random_range(0..3) {
+        0 => PriceCurve::Fixed,
+        1 => PriceCurve::Linear(rng.random_range(0..100)),
+        _ => PriceCurve::Exponential(rng.random_range(0.0..10.0)),
+    };
+    options.merge_bonus = if rng.random() {
+        MergeBonus::Majority
+    } else {
+        MergeBonus::Proportional
+    };
+    options.merge_rule = match rng.random_range(0..4) {
+        0 => MergeRule::Classic,
+        1 => MergeRule::PlayerChoice,
+        2 => MergeRule::HighestPrice,
+        _ => MergeRule::Random,
+    };
+    options.dividend_schedule = if rng.random() {
+        DividendSchedule::EachRound
+    } else {
+        DividendSchedule::EveryMove
+    };
+    options.dividend_percentage_per_size = rng.random_range(0.0..2.0);
+    options.suspend_dividends_after_merge = rng.random();
+    options.record_history = rng.random();
+
+    options
+}
+
+/// Panic with every invariant violation, if there are any.
+pub fn check(game: &StarLanes, action: &str) {
+    let violations = game.validate();
+
+    assert!(
+        violations.is_empty(),
+        "after {action}: {}",
+        violations
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join("; ")
+    );
+}
+
+/// Panic if the map's geometry queries disagree with each other or with the game.
+fn check_geometry(game: &StarLanes) {
+    let map = &game.map;
+
+    for co_num in map.companies_on_map() {
+        let components = map.company_components(co_num);
+        assert_eq!(components.len(), 1, "company {co_num} is in pieces");
+        assert_eq!(components[0].len(), map.company_cell_count(co_num));
+
+        let (Point(top, left), Point(bottom, right)) = map
+            .company_bounds(co_num)
+            .expect("company on the map has no bounds");
+        for &Point(r, c) in &components[0] {
+            assert!((top..=bottom).contains(&r) && (left..=right).contains(&c));
+        }
+
+        for Point(r, c) in map.company_frontier(co_num) {
+            assert_eq!(
+                map.get(r, c),
+                MapCell::Space,
+                "frontier cell {r},{c} isn't empty"
+            );
+        }
+    }
+
+    for (Point(r, c), companies) in map.merge_cells() {
+        assert!(companies.len() > 1, "merge cell {r},{c} has one company");
+
+        if game.is_playable(Point(r, c)) {
+            assert!(
+                matches!(game.preview_move(Point(r, c)), MovePreview::Merge(_)),
+                "merge cell {r},{c} doesn't preview as a merge"
+            );
+        }
+    }
+}
+
+/// Panic if a move didn't do what its preview said it would.
+fn check_preview(game: &StarLanes, move_point: Point, preview: &MovePreview, events: &[Event]) {
+    let Point(r, c) = move_point;
+    let cell = game.map.get(r, c);
+
+    let splits = |co_num: usize| {
+        events
+            .iter()
+            .filter(|e| matches!(e, Event::Split(n, _) if *n == co_num))
+            .count() as u32
+    };
+
+    let check_company = |company: &CompanyPreview| {
+        assert_eq!(
+            cell,
+            MapCell::Company(company.company as u32),
+            "wrong company"
+        );
+        assert_eq!(
+            game.get_company(company.company).share_price,
+            company.share_price,
+            "wrong share price"
+        );
+        assert_eq!(splits(company.company), company.splits, "wrong split count");
+    };
+
+    match preview {
+        MovePreview::Outpost => assert_eq!(cell, MapCell::Outpost, "no outpost"),
+
+        MovePreview::Growth(company) | MovePreview::NewCompany(company) => check_company(company),
+
+        MovePreview::Merge(merges) => {
+            let merge = merges
+                .iter()
+                .find(|m| cell == MapCell::Company(m.survivor.company as u32))
+                .expect("unexpected survivor");
+
+            check_company(&merge.survivor);
+
+            let merged: Vec<_> = events
+                .iter()
+                .filter_map(|e| match e {
+                    Event::Merge(survivor, absorbed, info) => Some((*survivor, *absorbed, info)),
+                    _ => None,
+                })
+                .collect();
+
+            assert_eq!(merged.len(), merge.absorbed.len(), "wrong merge count");
+
+            for ((survivor, absorbed, info), (co_num, preview_info)) in
+                merged.into_iter().zip(&merge.absorbed)
+            {
+                assert_eq!(survivor, merge.survivor.company, "wrong survivor");
+                assert_eq!(absorbed, *co_num, "wrong absorbed company");
+
+                for (actual, predicted) in info.iter().zip(preview_info) {
+                    assert_eq!(
+                        (actual.old_stock, actual.new_stock, actual.bonus_paid),
+                        (
+                            predicted.old_stock,
+                            predicted.new_stock,
+                            predicted.bonus_paid
+                        ),
+                        "wrong merge info"
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Snapshot of the current player's cash, loan, and holdings, to check failed actions leave the
+/// game untouched.
+fn wallet(game: &StarLanes) -> (i64, i64, Vec<i64>) {
+    let player = game.get_current_player();
+
+    (
+        player.get_cash(),
+        player.get_loan(),
+        player.get_all_holdings().to_vec(),
+    )
+}
+
+/// Start a game with the given options.
+pub fn start(options: StarLanesOptions) -> StarLanes {
+    let mut game = StarLanes::new_with_options(options);
+
+    game.reset();
+    check(&game, "reset");
+
+    game
+}
+
+/// Play a game to the end with the given options, making every choice with `chooser`.
+pub fn play(options: StarLanesOptions, chooser: &mut impl Chooser) -> StarLanes {
+    let mut game = start(options);
+
+    finish(&mut game, chooser);
+
+    game
+}
+
+/// Play a game to the end from wherever it is, making every choice with `chooser`.
+pub fn finish(game: &mut StarLanes, chooser: &mut impl Chooser) {
+    let mut turns = 0;
+
+    while play_turn(game, chooser) {
+        turns += 1;
+        assert!(turns < MAX_TURNS, "game never ended");
+    }
+
+    assert!(game.get_game_over_reason().is_some());
+}
+
+/// Draw the current player's moves, checking them against their hand, which should start with
+/// `kept`. Running out of moves is caught at the start of the turn, so there are always enough.
+fn draw_moves(game: &mut StarLanes, kept: &[Point]) -> Vec<Point> {
+    let player_num = game.get_current_player_index();
+    let moves = game.get_moves();
+
+    assert!(!game.game_is_over(), "ran out of moves after begin_turn");
+
+    assert_eq!(moves, game.view().get_candidate_moves());
+    assert_eq!(moves.len(), game.options.get_candidate_move_count());
+    if game.options.persistent_hands {
+        assert_eq!(moves, game.get_hand(player_num), "moves aren't the hand");
+        assert!(moves.starts_with(kept), "hand lost playable moves");
+    } else {
+        assert!(
+            game.get_hand(player_num).is_empty(),
+            "hand without persistent hands"
+        );
+    }
+    for (i, &Point(r, c)) in moves.iter().enumerate() {
+        assert!(game.is_playable(Point(r, c)), "unplayable move {r},{c}");
+        assert!(!moves[..i].contains(&Point(r, c)), "duplicate move {r},{c}");
+    }
+
+    check_geometry(game);
+
+    moves
+}
+
+/// Make one of the moves, choosing the survivor of any tied merge, and check it did what its
+/// preview said.
+fn play_move(game: &mut StarLanes, chooser: &mut impl Chooser, moves: &[Point]) {
+    let player_num = game.get_current_player_index();
+
+    let move_point = moves[chooser.pick(moves.len())];
+    let preview = game.preview_move(move_point);
+    let mut events = game.make_move(move_point);
+    check(game, "make_move");
+    assert!(
+        !game.get_hand(player_num).contains(&move_point),
+        "played move still in hand"
+    );
+
+    if game.must_choose_survivor() {
+        let choices = game.get_survivor_choices();
+        assert!(choices.len() > 1, "no tie to break");
+
+        events = game.choose_survivor(choices[chooser.pick(choices.len())]);
+        check(game, "choose_survivor");
+    }
+
+    check_preview(game, move_point, &preview, &events);
+}
+
+/// Play one turn, making every choice with `chooser`. Returns false once the game is over.
+pub fn play_turn(game: &mut StarLanes, chooser: &mut impl Chooser) -> bool {
+    assert_eq!(game.view().get_phase(), GameState::BeginTurn);
+
+    game.begin_turn();
+    check(game, "begin_turn");
+
+    if game.game_is_over() {
+        assert_eq!(
+            game.get_game_over_reason(),
+            Some(GameOverReason::NoMovesLeft)
+        );
+        return false;
+    }
+
+    let player_num = game.get_current_player_index();
+
+    if game.get_current_player().is_bankrupt() {
+        assert!(
+            game.get_hand(player_num).is_empty(),
+            "bankrupt player has a hand"
+        );
+        return end_turn(game, "end_turn for a bankrupt player");
+    }
+
+    let kept: Vec<Point> = game
+        .get_hand(player_num)
+        .iter()
+        .copied()
+        .filter(|&point| game.is_playable(point))
+        .collect();
+
+    let mut moves = draw_moves(game, &kept);
+
+    if let Some(fee) = game.options.reroll_fee
+        && chooser.pick(4) == 0
+    {
+        let before = wallet(game);
+
+        match game.reroll() {
+            Ok(_) => {
+                check(game, "reroll");
+                assert_eq!(wallet(game).0, before.0 - fee, "reroll fee not paid");
+                assert!(
+                    matches!(game.reroll(), Err(RerollError::AlreadyRerolled)),
+                    "rerolled twice in a turn"
+                );
+
+                // The whole hand was thrown away, so nothing is kept.
+                moves = draw_moves(game, &[]);
+            }
+            Err(_) => assert_eq!(wallet(game), before, "failed reroll changed the wallet"),
+        }
+    }
+
+    if game.options.allow_pass && chooser.pick(8) == 0 {
+        let hand = game.get_hand(player_num).to_vec();
+
+        game.pass_move();
+        check(game, "pass_move");
+        assert_eq!(game.get_hand(player_num), hand, "passing changed the hand");
+    } else {
+        play_move(game, chooser, &moves);
+    }
+
+    if game.options.loans {
+        let before = wallet(game);
+
+        if game.borrow(chooser.amount()).is_err() {
+            assert_eq!(wallet(game), before, "failed borrow changed the wallet");
+        }
+        check(game, "borrow");
+    }
+
+    if game.options.team_transfers {
+        for teammate in game.get_teammates(player_num) {
+            let before = wallet(game);
+
+            if game
+                .transfer_cash(teammate, chooser.amount().max(0))
+                .is_err()
+            {
+                assert_eq!(wallet(game), before, "failed transfer changed the wallet");
+            }
+            check(game, "transfer_cash");
+        }
+    }
+
+    let trade_companies: Vec<usize> = (0..game.get_companies().len())
+        .filter(|&i| game.get_company(i).in_use)
+        .collect();
+
+    for co_num in trade_companies {
+        let before = wallet(game);
+
+        if game.trade(co_num, chooser.amount()).is_err() {
+            assert_eq!(wallet(game), before, "failed trade changed the wallet");
+
+            // Passing always works, and moves on to the next company.
+            game.trade(co_num, 0).expect("passing on a trade failed");
+        }
+        check(game, "trade");
+    }
+
+    end_turn(game, "end_turn")
+}
+
+/// End the turn, checking the turn count goes down. Returns false if the game is over.
+fn end_turn(game: &mut StarLanes, action: &str) -> bool {
+    let turns_remaining = game.view().get_turns_remaining();
+
+    game.end_turn();
+    check(game, action);
+
+    if game.game_is_over() {
+        assert_eq!(game.view().get_phase(), GameState::GameOver);
+        assert_eq!(game.view().get_turns_remaining(), 0);
+        return false;
+    }
+
+    // Bankruptcies change the count with a round limit.
+    if game.options.max_rounds.is_none() {
+        assert_eq!(game.view().get_turns_remaining(), turns_remaining - 1);
+    }
+
+    true
+}
@@ -0,0 +1,145 @@
+//! Tests of the price and holdings history and its CSV and JSON exports.
+
+mod setup;
+
+use setup::{next_turn, start, trade};
+use starlanes::map::Point;
+use starlanes::starlanes::{StarLanes, StarLanesOptions};
+
+/// Play two turns with history recording on and two companies. The first player founds A at $600
+/// a share, getting a $150 dividend, and buys 2 more shares. The second grows A to $700 and buys
+/// 1 share.
+///
+/// Returns the game and the first player's number.
+fn play() -> (StarLanes, usize) {
+    let mut options = StarLanesOptions::new();
+    options.record_history = true;
+    options.company_count = 2;
+
+    let mut game = start(options, &["....", ".*..", "...."]);
+    let first = game.get_current_player_index();
+
+    game.make_move(Point(0, 1));
+    trade(&mut game, 0, 2).unwrap();
+
+    next_turn(&mut game);
+    game.make_move(Point(0, 2));
+    trade(&mut game, 0, 1).unwrap();
+
+    game.end_turn();
+
+    (game, first)
+}
+
+/// Put each player's rows in player number order.
+fn by_player(first: usize, first_row: &str, second_row: &str) -> String {
+    if first == 0 {
+        format!("{first_row}\n{second_row}\n")
+    } else {
+        format!("{second_row}\n{first_row}\n")
+    }
+}
+
+#[test]
+fn snapshots_every_turn() {
+    let (game, first) = play();
+    let snapshots = &game.get_history().snapshots;
+
+    assert_eq!(snapshots.len(), 3);
+    assert_eq!(
+        snapshots
+            .iter()
+            .map(|s| (s.turn, s.round))
+            .collect::<Vec<_>>(),
+        vec![(0, 0), (1, 0), (2, 1)]
+    );
+
+    let last = &snapshots[2];
+    assert!(last.companies[0].in_use);
+    assert_eq!(last.companies[0].size, 2);
+    assert_eq!(last.companies[0].share_price, 700);
+    assert!(!last.companies[1].in_use);
+
+    let player = &last.players[first];
+    assert_eq!(player.cash, 4950);
+    assert_eq!(player.holdings, vec![7, 0]);
+    assert_eq!(player.net_worth, 4950 + 7 * 700);
+
+    let player = &last.players[1 - first];
+    assert_eq!(player.cash, 5300);
+    assert_eq!(player.holdings, vec![1, 0]);
+    assert_eq!(player.net_worth, 6000);
+}
+
+#[test]
+fn companies_csv() {
+    let (game, _) = play();
+
+    assert_eq!(
+        game.get_history().companies_to_csv(),
+        "turn,round,company,in_use,size,share_price\n\
+         0,0,0,false,0,100\n\
+         0,0,1,false,0,100\n\
+         1,0,0,true,1,600\n\
+         1,0,1,false,0,100\n\
+         2,1,0,true,2,700\n\
+         2,1,1,false,0,100\n"
+    );
+}
+
+#[test]
+fn players_csv() {
+    let (game, first) = play();
+    let second = 1 - first;
+
+    let expected = String::from("turn,round,player,cash,loan,net_worth,holdings_0,holdings_1\n")
+        + &by_player(
+            first,
+            &format!("0,0,{first},6000,0,6000,0,0"),
+            &format!("0,0,{second},6000,0,6000,0,0"),
+        )
+        + &by_player(
+            first,
+            &format!("1,0,{first},4950,0,9150,7,0"),
+            &format!("1,0,{second},6000,0,6000,0,0"),
+        )
+        + &by_player(
+            first,
+            &format!("2,1,{first},4950,0,9850,7,0"),
+            &format!("2,1,{second},5300,0,6000,1,0"),
+        );
+
+    assert_eq!(game.get_history().players_to_csv(), expected);
+}
+
+#[test]
+fn json() {
+    let (game, first) = play();
+
+    let json = game.get_history().to_json();
+    let lines: Vec<&str> = json.lines().collect();
+
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[0], "[");
+    assert_eq!(lines[4], "]");
+
+    let players = by_player(
+        first,
+        "{\"cash\":4950,\"loan\":0,\"net_worth\":9850,\"holdings\":[7,0]}",
+        "{\"cash\":5300,\"loan\":0,\"net_worth\":6000,\"holdings\":[1,0]}",
+    )
+    .trim_end()
+    .replace('\n', ",");
+
+    assert_eq!(
+        lines[3],
+        format!(
+            "  {{\"turn\":2,\"round\":1,\"companies\":[\
+             {{\"in_use\":true,\"size\":2,\"share_price\":700}},\
+             {{\"in_use\":false,\"size\":0,\"share_price\":100}}],\
+             \"players\":[{players}]}}"
+        )
+    );
+    assert!(lines[1].ends_with("},"));
+    assert!(lines[2].ends_with("},"));
+}
@@ -0,0 +1,135 @@
+//! Tests of the players' transaction ledgers.
+
+mod setup;
+
+use setup::{next_turn, start, trade};
+use starlanes::map::Point;
+use starlanes::player::LedgerReason::{self, *};
+use starlanes::starlanes::{StarLanes, StarLanesOptions};
+
+/// A ledger entry as a tuple of turn, reason, company, shares and cash, for easy comparison.
+type Entry = (usize, LedgerReason, Option<usize>, i64, i64);
+
+/// Return a player's ledger as tuples.
+fn ledger(game: &StarLanes, player_num: usize) -> Vec<Entry> {
+    game.get_player(player_num)
+        .get_ledger()
+        .iter()
+        .map(|e| (e.turn, e.reason, e.company, e.shares, e.cash))
+        .collect()
+}
+
+/// Check that the cash column adds up to the player's cash, and the share columns to what they
+/// hold in every company in play. Holdings in absorbed companies linger until the company is
+/// founded again, but the ledger counts them as converted.
+fn check_ledger(game: &StarLanes, player_num: usize) {
+    let player = game.get_player(player_num);
+    let cash: i64 = player.get_ledger().iter().map(|e| e.cash).sum();
+
+    assert_eq!(game.options.starting_cash + cash, player.get_cash());
+
+    for co_num in 0..game.get_companies().len() {
+        if !game.get_company(co_num).in_use {
+            continue;
+        }
+
+        let shares: i64 = player
+            .get_ledger()
+            .iter()
+            .filter(|e| e.company == Some(co_num))
+            .map(|e| e.shares)
+            .sum();
+
+        assert_eq!(shares, player.get_holdings(co_num), "company {co_num}");
+    }
+}
+
+#[test]
+fn trades_dividends_and_merges() {
+    let mut game = start(StarLanesOptions::new(), &["....", "*..*", "...."]);
+    let first = game.get_current_player_index();
+    let second = 1 - first;
+
+    // Turn 0: the first player founds A at $600.
+    game.make_move(Point(0, 0));
+
+    // Turn 1: the second player founds B at $600 and buys 3 shares.
+    next_turn(&mut game);
+    game.make_move(Point(0, 3));
+    trade(&mut game, 1, 3).unwrap();
+
+    // Turn 2: A grows to $700, and the first player buys 2 shares of B.
+    next_turn(&mut game);
+    game.make_move(Point(0, 1));
+    trade(&mut game, 1, 2).unwrap();
+
+    // Turn 3: an outpost.
+    next_turn(&mut game);
+    game.make_move(Point(2, 1));
+
+    // Turn 4: A absorbs B, and the first player sells a share of A at $1,300.
+    next_turn(&mut game);
+    game.make_move(Point(0, 2));
+    trade(&mut game, 0, -1).unwrap();
+
+    assert_eq!(
+        ledger(&game, first),
+        vec![
+            (0, FounderShares, Some(0), 5, 0),
+            (0, Dividend, Some(0), 0, 150),
+            (2, Dividend, Some(0), 0, 175),
+            (2, Purchase, Some(1), 2, -1200),
+            (4, MergeConversion(1), Some(1), -2, 0),
+            (4, MergeConversion(1), Some(0), 1, 0),
+            (4, MergeBonus(1), Some(1), 0, 1200),
+            (4, Dividend, Some(0), 0, 390),
+            (4, Sale, Some(0), -1, 1300),
+        ]
+    );
+
+    assert_eq!(
+        ledger(&game, second),
+        vec![
+            (1, FounderShares, Some(1), 5, 0),
+            (1, Dividend, Some(1), 0, 150),
+            (1, Purchase, Some(1), 3, -1800),
+            (3, Dividend, Some(1), 0, 240),
+            (4, MergeConversion(1), Some(1), -8, 0),
+            (4, MergeConversion(1), Some(0), 4, 0),
+            (4, MergeBonus(1), Some(1), 0, 4800),
+        ]
+    );
+
+    for player_num in [first, second] {
+        check_ledger(&game, player_num);
+    }
+}
+
+#[test]
+fn splits() {
+    let mut game = start(StarLanesOptions::new(), &[".**.", "*..*", ".**."]);
+    let first = game.get_current_player_index();
+
+    // Turn 0: the first player founds A at $1,600 and buys 3 shares.
+    game.make_move(Point(1, 1));
+    trade(&mut game, 0, 3).unwrap();
+
+    // Turn 1: A grows to $3,200 and splits.
+    next_turn(&mut game);
+    game.make_move(Point(1, 2));
+
+    assert_eq!(
+        ledger(&game, first),
+        vec![
+            (0, FounderShares, Some(0), 5, 0),
+            (0, Dividend, Some(0), 0, 400),
+            (0, Purchase, Some(0), 3, -4800),
+            (1, Split, Some(0), 8, 0),
+        ]
+    );
+
+    // Nothing happened to the second player, who had no stock to split.
+    assert!(ledger(&game, 1 - first).is_empty());
+
+    check_ledger(&game, first);
+}
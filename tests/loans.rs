@@ -0,0 +1,165 @@
+//! Tests of loans: borrowing, interest, margin calls and bankruptcy.
+
+mod setup;
+
+use setup::{next_turn, start, trade};
+use starlanes::event::Event;
+use starlanes::map::Point;
+use starlanes::player::LedgerReason;
+use starlanes::starlanes::{GameState, LoanError, StarLanes, StarLanesOptions};
+
+/// Start a game with loans and no starting cash. The first player founds a company next to a
+/// star at $600 a share, which leaves them with 5 shares and a $150 dividend. They borrow $1,500,
+/// the most they can against $3,000 of stock, and spend $1,200 on 2 more shares, leaving them
+/// $450 in cash.
+///
+/// Every turn, there's a pirate raid on the company that takes `raid_percentage` off its price.
+/// Returns the game, ready for the other player to move, and the borrower's player number.
+fn borrow(interest_percentage: f32, raid_percentage: f32) -> (StarLanes, usize) {
+    let mut options = StarLanesOptions::new();
+    options.loans = true;
+    options.starting_cash = 0;
+    options.loan_interest_percentage = interest_percentage;
+    options.random_events = true;
+    options.pirate_raid_probability = 1.0;
+    options.pirate_raid_percentage = raid_percentage;
+    options.trade_boom_probability = 0.0;
+    options.supernova_probability = 0.0;
+    options.subsidy_probability = 0.0;
+
+    let mut game = start(options, &[".....", ".*...", "....."]);
+    let player_num = game.get_current_player_index();
+
+    game.make_move(Point(0, 1));
+    assert_eq!(game.get_current_player().get_cash(), 150);
+    assert_eq!(game.get_credit_limit(player_num), 1500);
+
+    assert!(matches!(game.borrow(1501), Err(LoanError::OverCreditLimit)));
+    game.borrow(1500).unwrap();
+    assert!(matches!(game.borrow(-1501), Err(LoanError::OverRepayment)));
+    trade(&mut game, 0, 2).unwrap();
+
+    let player = game.get_current_player();
+    assert_eq!(player.get_cash(), 450);
+    assert_eq!(player.get_loan(), 1500);
+    assert_eq!(player.get_holdings(0), 7);
+
+    // The other player puts down an outpost.
+    next_turn(&mut game);
+    game.make_move(Point(2, 4));
+
+    (game, player_num)
+}
+
+/// Check that the ledger's cash and loan columns add up to what the player has and owes.
+fn check_ledger(game: &StarLanes, player_num: usize) {
+    let player = game.get_player(player_num);
+    let ledger = player.get_ledger();
+
+    assert_eq!(
+        ledger.iter().map(|e| e.cash).sum::<i64>(),
+        player.get_cash()
+    );
+    assert_eq!(
+        ledger.iter().map(|e| e.loan).sum::<i64>(),
+        player.get_loan()
+    );
+}
+
+#[test]
+fn interest_comes_out_of_cash_then_adds_to_the_loan() {
+    let (mut game, player_num) = borrow(50.0, 0.0);
+
+    let events = next_turn(&mut game);
+
+    assert!(
+        events
+            .iter()
+            .any(|e| matches!(e, Event::LoanInterest(p, 750) if *p == player_num))
+    );
+    assert!(!events.iter().any(|e| matches!(e, Event::LoanCalled(..))));
+
+    // $450 of the $750 is paid in cash, and the other $300 is borrowed.
+    let player = game.get_player(player_num);
+    assert_eq!(player.get_cash(), 0);
+    assert_eq!(player.get_loan(), 1800);
+
+    let entry = player.get_ledger().last().unwrap();
+    assert_eq!(entry.reason, LedgerReason::LoanInterest);
+    assert_eq!(entry.cash, -450);
+    assert_eq!(entry.loan, 300);
+
+    check_ledger(&game, player_num);
+}
+
+#[test]
+fn margin_calls_sell_just_enough_stock() {
+    // The price drops from $600 to $360 to $216, which makes the stock worth $1,512, and the
+    // $1,500 loan more than the 75% that gets it called.
+    let (mut game, player_num) = borrow(0.0, 40.0);
+
+    let events = next_turn(&mut game);
+    assert_eq!(game.get_company(0).share_price, 216);
+
+    let Some(Event::LoanCalled(p, sales)) =
+        events.iter().find(|e| matches!(e, Event::LoanCalled(..)))
+    else {
+        panic!("loan wasn't called");
+    };
+    assert_eq!(*p, player_num);
+
+    // The $450 in cash brings the loan to $1,050. Selling 3 shares for $648 brings it to $402,
+    // under half of the $864 the other 4 are worth. 2 shares wouldn't have been enough.
+    assert_eq!(sales.len(), 1);
+    assert_eq!(sales[0].company, 0);
+    assert_eq!(sales[0].shares, 3);
+    assert_eq!(sales[0].proceeds, 648);
+
+    let player = game.get_player(player_num);
+    assert_eq!(player.get_cash(), 0);
+    assert_eq!(player.get_loan(), 402);
+    assert_eq!(player.get_holdings(0), 4);
+    assert!(!player.is_bankrupt());
+    assert_eq!(game.view().get_phase(), GameState::Move);
+
+    let reasons: Vec<LedgerReason> = player.get_ledger().iter().map(|e| e.reason).collect();
+    assert_eq!(
+        reasons[reasons.len() - 2..],
+        [LedgerReason::Loan, LedgerReason::ForcedSale]
+    );
+
+    check_ledger(&game, player_num);
+}
+
+#[test]
+fn worthless_stock_means_bankruptcy() {
+    // The price drops from $600 to $60 to $6, so selling all 7 shares only brings in $42.
+    let (mut game, player_num) = borrow(0.0, 90.0);
+
+    let events = next_turn(&mut game);
+
+    assert!(
+        events
+            .iter()
+            .any(|e| matches!(e, Event::Bankrupt(p) if *p == player_num))
+    );
+
+    let player = game.get_player(player_num);
+    assert!(player.is_bankrupt());
+    assert_eq!(player.get_cash(), 0);
+    assert_eq!(player.get_loan(), 0);
+    assert_eq!(player.get_holdings(0), 0);
+    assert_eq!(game.view().get_phase(), GameState::EndTurn);
+
+    // The stock was all sold, so only the $1,008 still owed is written off.
+    let written_off: Vec<_> = player
+        .get_ledger()
+        .iter()
+        .filter(|e| e.reason == LedgerReason::Bankruptcy)
+        .collect();
+    assert_eq!(written_off.len(), 1);
+    assert_eq!(written_off[0].company, None);
+    assert_eq!(written_off[0].loan, -1008);
+
+    check_ledger(&game, player_num);
+}
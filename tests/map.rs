@@ -0,0 +1,127 @@
+//! Tests of the map's lane geometry queries on hand-drawn maps.
+
+use starlanes::map::{Map, MapCell, Point};
+
+/// Make a map from rows of text: `.` is space, `*` a star, `+` an outpost, and `A`, `B`, etc.
+/// are companies.
+fn draw(rows: &[&str]) -> Map {
+    let mut map = Map::new_with_params(rows[0].len(), rows.len(), 0.0);
+
+    for (r, row) in rows.iter().enumerate() {
+        for (c, ch) in row.chars().enumerate() {
+            let cell = match ch {
+                '.' => MapCell::Space,
+                '*' => MapCell::Star,
+                '+' => MapCell::Outpost,
+                'A'..='Z' => MapCell::Company(ch as u32 - 'A' as u32),
+                _ => panic!("unknown map character {ch}"),
+            };
+
+            map.set(r, c, cell);
+        }
+    }
+
+    map
+}
+
+#[test]
+fn cells_and_components() {
+    let map = draw(&[
+        "AA..B", //
+        ".A.*B", //
+        "..A..", //
+        "*.AA.",
+    ]);
+
+    assert_eq!(map.company_cell_count(0), 6);
+    assert_eq!(
+        map.company_components(0),
+        vec![
+            vec![Point(0, 0), Point(0, 1), Point(1, 1)],
+            vec![Point(2, 2), Point(3, 2), Point(3, 3)],
+        ]
+    );
+    assert_eq!(
+        map.company_components(1),
+        vec![vec![Point(0, 4), Point(1, 4)]]
+    );
+    assert!(map.company_components(2).is_empty());
+    assert_eq!(map.companies_on_map().collect::<Vec<_>>(), vec![0, 1]);
+}
+
+#[test]
+fn adjacent_stars_and_bounds() {
+    let map = draw(&[
+        "*A*..", //
+        ".A...", //
+        ".AA*.", //
+        "...B*",
+    ]);
+
+    // The star at 0,2 touches the company twice but counts once.
+    assert_eq!(map.company_adjacent_stars(0), 3);
+    assert_eq!(map.company_adjacent_stars(1), 2);
+    assert_eq!(map.company_adjacent_stars(4), 0);
+
+    assert_eq!(map.company_bounds(0), Some((Point(0, 1), Point(2, 2))));
+    assert_eq!(map.company_bounds(1), Some((Point(3, 3), Point(3, 3))));
+    assert_eq!(map.company_bounds(2), None);
+}
+
+#[test]
+fn star_cells() {
+    let mut map = draw(&[
+        "*A*..", //
+        ".....", //
+        "...*.",
+    ]);
+
+    assert_eq!(
+        map.star_cells().collect::<Vec<_>>(),
+        vec![Point(0, 0), Point(0, 2), Point(2, 3)]
+    );
+
+    map.set(0, 2, MapCell::Space);
+    assert_eq!(
+        map.star_cells().collect::<Vec<_>>(),
+        vec![Point(0, 0), Point(2, 3)]
+    );
+}
+
+#[test]
+fn frontier() {
+    let map = draw(&[
+        "+A*", //
+        ".A.", //
+        "B..",
+    ]);
+
+    assert_eq!(
+        map.company_frontier(0),
+        vec![Point(1, 0), Point(1, 2), Point(2, 1)]
+    );
+    assert_eq!(map.company_frontier(1), vec![Point(1, 0), Point(2, 1)]);
+    assert!(map.company_frontier(2).is_empty());
+}
+
+#[test]
+fn merge_cells() {
+    let map = draw(&[
+        "A.B.", //
+        "..+.", //
+        "C.D.", //
+        ".C..",
+    ]);
+
+    // The companies are listed north, south, east, west, so 2,1 finds C to the south before D to
+    // the east. The outpost at 1,2 doesn't count.
+    assert_eq!(
+        map.merge_cells(),
+        vec![
+            (Point(0, 1), vec![1, 0]),
+            (Point(1, 0), vec![0, 2]),
+            (Point(2, 1), vec![2, 3]),
+            (Point(3, 2), vec![3, 2]),
+        ]
+    );
+}
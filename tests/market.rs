@@ -0,0 +1,173 @@
+//! Tests of trade quotes under each price curve.
+
+mod setup;
+
+use setup::{next_turn, start, trade};
+use starlanes::map::Point;
+use starlanes::starlanes::{PriceCurve, StarLanes, StarLanesOptions, TradeError, TradeQuote};
+
+/// Start a game with the given curve and starting cash, and found a company next to a star, so
+/// it starts at $600 a share.
+fn found(price_curve: PriceCurve, starting_cash: i64) -> StarLanes {
+    let mut options = StarLanesOptions::new();
+    options.price_curve = price_curve;
+    options.starting_cash = starting_cash;
+
+    let mut game = start(options, &["....", ".*..", "...."]);
+
+    game.make_move(Point(0, 1));
+    assert_eq!(game.get_company(0).share_price, 600);
+
+    game
+}
+
+/// Price a trade one share at a time, the slow way.
+fn share_by_share(pct: f32, mut price: u64, amount: i64) -> TradeQuote {
+    let step = |price: u64| ((pct / 100.0 * price as f32).round() as u64).max(1);
+    let mut total_cost = 0;
+
+    for _ in 0..amount.unsigned_abs() {
+        if amount > 0 {
+            total_cost += price as i64;
+            price += step(price);
+        } else {
+            price = price.saturating_sub(step(price)).max(1);
+            total_cost -= price as i64;
+        }
+    }
+
+    TradeQuote {
+        total_cost,
+        new_share_price: price,
+    }
+}
+
+#[test]
+fn fixed_prices_never_move() {
+    let mut game = found(PriceCurve::Fixed, 6000);
+
+    let quote = game.quote(0, 10);
+    assert_eq!(quote.total_cost, 6000);
+    assert_eq!(quote.new_share_price, 600);
+
+    assert_eq!(game.quote(0, -10).total_cost, -6000);
+    assert_eq!(game.quote(0, i64::MAX).total_cost, i64::MAX);
+
+    let cash = game.get_current_player().get_cash();
+    trade(&mut game, 0, 10).unwrap();
+    assert_eq!(game.get_current_player().get_cash(), cash - 6000);
+    assert_eq!(game.get_company(0).share_price, 600);
+}
+
+#[test]
+fn linear_round_trip_is_a_wash() {
+    let mut game = found(PriceCurve::Linear(10), 6000);
+
+    // $600 + $610 + $620.
+    let quote = game.quote(0, 3);
+    assert_eq!(quote.total_cost, 1830);
+    assert_eq!(quote.new_share_price, 630);
+
+    let cash = game.get_current_player().get_cash();
+    trade(&mut game, 0, 3).unwrap();
+    assert_eq!(game.get_current_player().get_cash(), cash - 1830);
+    assert_eq!(game.get_company(0).share_price, 630);
+
+    let quote = game.quote(0, -3);
+    assert_eq!(quote.total_cost, -1830);
+    assert_eq!(quote.new_share_price, 600);
+}
+
+#[test]
+fn linear_sales_bottom_out() {
+    let game = found(PriceCurve::Linear(100), 6000);
+
+    // $500 + $400 + $300 + $200 + $100, then $1 apiece for the rest.
+    let quote = game.quote(0, -10);
+    assert_eq!(quote.total_cost, -1505);
+    assert_eq!(quote.new_share_price, 1);
+
+    let quote = game.quote(0, -i64::MAX);
+    assert_eq!(quote.total_cost, i64::MIN);
+    assert_eq!(quote.new_share_price, 1);
+}
+
+#[test]
+fn exponential_round_trip_loses_money() {
+    let mut game = found(PriceCurve::Exponential(10.0), 6000);
+
+    // $600 + $660 + $726.
+    let quote = game.quote(0, 3);
+    assert_eq!(quote.total_cost, 1986);
+    assert_eq!(quote.new_share_price, 799);
+
+    let cash = game.get_current_player().get_cash();
+    trade(&mut game, 0, 3).unwrap();
+    assert_eq!(game.get_current_player().get_cash(), cash - 1986);
+
+    // Each sale takes 10% off the higher price: $719 + $647 + $582.
+    let quote = game.quote(0, -3);
+    assert_eq!(quote.total_cost, -1948);
+    assert_eq!(quote.new_share_price, 582);
+}
+
+#[test]
+fn exponential_matches_share_by_share() {
+    for pct in [0.0, 0.4, 1.0, 3.0, 10.0, 50.0] {
+        let game = found(PriceCurve::Exponential(pct), i64::MAX);
+
+        for amount in (-3000..-100).step_by(97).chain(-100..=60) {
+            assert_eq!(
+                game.quote(0, amount),
+                share_by_share(pct, 600, amount),
+                "{amount} shares at {pct}%"
+            );
+        }
+    }
+}
+
+#[test]
+fn huge_quotes_are_quick() {
+    // The price rises a dollar a share: $600 + $601 + ... for 100,000,000 shares.
+    let game = found(PriceCurve::Exponential(0.0), i64::MAX);
+
+    let n: i64 = 100_000_000;
+    let quote = game.quote(0, n);
+    assert_eq!(quote.total_cost, n * 600 + n * (n - 1) / 2);
+    assert_eq!(quote.new_share_price, 600 + n as u64);
+
+    assert_eq!(game.quote(0, i64::MAX).total_cost, i64::MAX);
+
+    let quote = game.quote(0, -i64::MAX);
+    assert_eq!(quote.total_cost, i64::MIN);
+    assert_eq!(quote.new_share_price, 1);
+
+    for pct in [0.001, 1.0] {
+        let game = found(PriceCurve::Exponential(pct), i64::MAX);
+        assert_eq!(game.quote(0, i64::MAX).total_cost, i64::MAX);
+    }
+
+    let game = found(PriceCurve::Linear(1), i64::MAX);
+    let quote = game.quote(0, n);
+    assert_eq!(quote.total_cost, n * 600 + n * (n - 1) / 2);
+    assert_eq!(quote.new_share_price, 600 + n as u64);
+}
+
+#[test]
+fn unaffordable_quotes_stop_at_the_players_cash() {
+    let mut game = found(PriceCurve::Exponential(10.0), 6000);
+
+    let quote = game.quote(0, i64::MAX);
+    assert!(quote.total_cost > 6000);
+    assert!(quote.total_cost < i64::MAX);
+
+    assert!(matches!(
+        trade(&mut game, 0, i64::MAX),
+        Err(TradeError::TooLittleCash)
+    ));
+
+    // Quotes are always for the current player.
+    trade(&mut game, 0, 0).unwrap();
+    next_turn(&mut game);
+    assert!(game.quote(0, i64::MAX).total_cost > 6000);
+}
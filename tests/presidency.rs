@@ -0,0 +1,114 @@
+//! Tests of company presidents and majority and minority merge bonuses.
+
+mod setup;
+
+use setup::{next_turn, start, trade};
+use starlanes::event::{Event, MergeInfo};
+use starlanes::map::Point;
+use starlanes::player::LedgerReason;
+use starlanes::starlanes::{MergeBonus, StarLanes, StarLanesOptions};
+
+/// Play a game where the first player founds A and the second founds B at $600 a share and buys
+/// 3 more shares of it, for 8 in all. A grows, the first player buys `first_shares` of B, and A
+/// absorbs B.
+///
+/// Returns the game, the first player's number, and the events from the merge.
+fn absorb(first_shares: i64) -> (StarLanes, usize, Vec<Event>) {
+    let mut options = StarLanesOptions::new();
+    options.merge_bonus = MergeBonus::Majority;
+    options.starting_cash = 100_000;
+
+    let mut game = start(options, &["....", "*..*", "...."]);
+    let first = game.get_current_player_index();
+    let second = 1 - first;
+
+    let events = game.make_move(Point(0, 0));
+    assert!(new_president(&events, 0, first));
+
+    next_turn(&mut game);
+    let events = game.make_move(Point(0, 3));
+    assert!(new_president(&events, 1, second));
+    trade(&mut game, 1, 3).unwrap();
+
+    next_turn(&mut game);
+    game.make_move(Point(0, 1));
+    let events = trade(&mut game, 1, first_shares).unwrap();
+    assert_eq!(new_president(&events, 1, first), first_shares > 8);
+
+    next_turn(&mut game);
+    game.make_move(Point(2, 1));
+
+    next_turn(&mut game);
+    let events = game.make_move(Point(0, 2));
+
+    (game, first, events)
+}
+
+/// Return true if the events include a player becoming president of a company.
+fn new_president(events: &[Event], co_num: usize, player_num: usize) -> bool {
+    events
+        .iter()
+        .any(|e| matches!(e, Event::NewPresident(c, p) if *c == co_num && *p == player_num))
+}
+
+/// Return the merge info for each player from the events.
+fn merge_info(events: &[Event]) -> &[MergeInfo] {
+    let Some(Event::Merge(0, 1, info)) = events.iter().find(|e| matches!(e, Event::Merge(..)))
+    else {
+        panic!("A didn't absorb B");
+    };
+
+    info
+}
+
+#[test]
+fn largest_holders_get_fixed_bonuses() {
+    let (game, first, events) = absorb(2);
+    let second = 1 - first;
+    let info = merge_info(&events);
+
+    // The majority bonus is 10 times B's $600 price, and the minority bonus 5 times.
+    assert_eq!(info[second].old_stock, 8);
+    assert_eq!(info[second].new_stock, 4);
+    assert_eq!(info[second].bonus_paid, 6000);
+    assert_eq!(info[first].old_stock, 2);
+    assert_eq!(info[first].new_stock, 1);
+    assert_eq!(info[first].bonus_paid, 3000);
+
+    // The bonuses are paid out in cash.
+    for (player_num, bonus) in [(second, 6000), (first, 3000)] {
+        let entry = game
+            .get_player(player_num)
+            .get_ledger()
+            .iter()
+            .find(|e| e.reason == LedgerReason::MergeBonus(1))
+            .unwrap();
+        assert_eq!(entry.cash, bonus);
+    }
+
+    assert_eq!(game.get_company(0).president, Some(first));
+    assert_eq!(game.get_company(1).president, None);
+}
+
+#[test]
+fn tied_holders_split_both_bonuses() {
+    let (game, first, events) = absorb(8);
+    let info = merge_info(&events);
+
+    assert_eq!(info[first].bonus_paid, 4500);
+    assert_eq!(info[1 - first].bonus_paid, 4500);
+
+    // The incumbent kept B through the tie, and A's president is unchanged by the merge.
+    assert!(!events.iter().any(|e| matches!(e, Event::NewPresident(..))));
+    assert_eq!(game.get_company(0).president, Some(first));
+}
+
+#[test]
+fn buying_the_most_stock_takes_the_presidency() {
+    let (game, first, events) = absorb(9);
+    let info = merge_info(&events);
+
+    assert_eq!(info[first].bonus_paid, 6000);
+    assert_eq!(info[1 - first].bonus_paid, 3000);
+    assert_eq!(game.get_player(first).get_holdings(0), 10);
+}
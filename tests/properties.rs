@@ -0,0 +1,115 @@
+//! Property tests of the engine: play lots of random games with random rules and random choices,
+//! checking invariants after every action.
+//!
+//! Set `STARLANES_GAMES` to play more or fewer games per test.
+
+mod common;
+
+use common::{Chooser, finish, play, play_turn, random_options, start};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use starlanes::starlanes::StarLanesOptions;
+
+/// Games played per test unless overridden.
+const DEFAULT_GAMES: u64 = 500;
+
+impl Chooser for StdRng {
+    fn next(&mut self) -> u64 {
+        self.random()
+    }
+}
+
+/// Number of games to play per test.
+fn game_count() -> u64 {
+    std::env::var("STARLANES_GAMES")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(DEFAULT_GAMES)
+}
+
+/// Games under the original rules.
+#[test]
+fn classic_games_hold_invariants() {
+    for seed in 0..game_count() {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut options = StarLanesOptions::new();
+
+        options.seed = Some(seed);
+        options.player_count = rng.random_range(2..=4);
+
+        play(options, &mut rng);
+    }
+}
+
+/// Games under every combination of house rules.
+#[test]
+fn variant_games_hold_invariants() {
+    for seed in 0..game_count() {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let options = random_options(&mut rng, seed);
+
+        play(options, &mut rng);
+    }
+}
+
+/// The same seed and choices always play out the same way.
+#[test]
+fn seeded_games_are_repeatable() {
+    for seed in 0..game_count() / 10 {
+        let worths: Vec<Vec<i64>> = (0..2)
+            .map(|_| {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let options = random_options(&mut rng, seed);
+                let game = play(options, &mut rng);
+
+                (0..game.options.player_count)
+                    .map(|p| game.get_net_worth(p))
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(worths[0], worths[1], "seed {seed} played out differently");
+    }
+}
+
+/// A game cloned partway through plays out the same as the original.
+#[test]
+fn cloned_games_play_out_the_same() {
+    for seed in 0..game_count() / 10 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let options = random_options(&mut rng, seed);
+        let mut game = start(options);
+
+        for _ in 0..rng.random_range(0..40) {
+            if !play_turn(&mut game, &mut rng) {
+                break;
+            }
+        }
+
+        let mut clone = game.clone();
+        let mut clone_rng = rng.clone();
+
+        if !game.game_is_over() {
+            finish(&mut game, &mut rng);
+            finish(&mut clone, &mut clone_rng);
+        }
+
+        let (view, clone_view) = (game.view(), clone.view());
+
+        assert_eq!(view.get_turn_number(), clone_view.get_turn_number());
+        assert_eq!(view.get_phase(), clone_view.get_phase());
+        assert_eq!(
+            format!("{:?}", view.get_map()),
+            format!("{:?}", clone_view.get_map()),
+            "seed {seed} maps differ"
+        );
+
+        for p in 0..game.options.player_count {
+            assert_eq!(
+                game.get_net_worth(p),
+                clone.get_net_worth(p),
+                "seed {seed} played out differently"
+            );
+        }
+    }
+}
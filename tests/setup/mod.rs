@@ -0,0 +1,67 @@
+//! Hand-built games for testing the engine's features one at a time. Games are played in wizard
+//! mode on a hand-drawn map, so every move and every amount is known ahead of time.
+
+use starlanes::event::Event;
+use starlanes::map::{Map, MapCell};
+use starlanes::starlanes::{GameState, StarLanes, StarLanesOptions, TradeError};
+
+/// Make a map from rows of text: `.` is space, `*` a star, `+` an outpost, and `A`, `B`, etc.
+/// are companies.
+fn draw(rows: &[&str]) -> Map {
+    let mut map = Map::new_with_params(rows[0].len(), rows.len(), 0.0);
+
+    for (r, row) in rows.iter().enumerate() {
+        for (c, ch) in row.chars().enumerate() {
+            let cell = match ch {
+                '.' => MapCell::Space,
+                '*' => MapCell::Star,
+                '+' => MapCell::Outpost,
+                'A'..='Z' => MapCell::Company(ch as u32 - 'A' as u32),
+                _ => panic!("unknown map character {ch}"),
+            };
+
+            map.set(r, c, cell);
+        }
+    }
+
+    map
+}
+
+/// Start a game on a hand-drawn map, ready for the first player to move. The map can't have
+/// companies on it, since the engine doesn't know about them yet. Two players are seated unless
+/// the options say otherwise.
+pub fn start(mut options: StarLanesOptions, rows: &[&str]) -> StarLanes {
+    if options.player_count == 0 {
+        options.player_count = 2;
+    }
+    options.seed = Some(1);
+    options.wizard_mode = true;
+
+    let mut game = StarLanes::new_with_options(options);
+
+    game.reset();
+    game.map = draw(rows);
+    game.begin_turn();
+
+    game
+}
+
+/// Trade in a company, passing on any companies before it.
+pub fn trade(game: &mut StarLanes, co_num: usize, amount: i64) -> Result<Vec<Event>, TradeError> {
+    while let GameState::Trade(i) = game.view().get_phase()
+        && i < co_num
+    {
+        game.trade(i, 0).expect("passing on a trade failed");
+    }
+
+    game.trade(co_num, amount)
+}
+
+/// End the current turn and begin the next, returning the events from both.
+pub fn next_turn(game: &mut StarLanes) -> Vec<Event> {
+    let mut events = game.end_turn();
+
+    events.extend(game.begin_turn());
+
+    events
+}
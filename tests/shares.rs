@@ -0,0 +1,130 @@
+//! Tests of the bank's share pool when share supply is limited.
+
+mod setup;
+
+use setup::{next_turn, start, trade};
+use starlanes::map::Point;
+use starlanes::starlanes::{GameState, StarLanes, StarLanesOptions, TradeError};
+
+/// Start a game with limited shares and plenty of cash.
+fn limited(rows: &[&str]) -> StarLanes {
+    let mut options = StarLanesOptions::new();
+    options.limited_shares = true;
+    options.starting_cash = 100_000;
+
+    start(options, rows)
+}
+
+/// Check that every share issued is held by the bank or a player.
+fn check_pool(game: &StarLanes, co_num: usize) {
+    let company = game.get_company(co_num);
+    let held: i64 = (0..2)
+        .map(|p| game.get_player(p).get_holdings(co_num))
+        .sum();
+
+    assert_eq!(company.bank_shares + held, company.shares_issued);
+}
+
+#[test]
+fn the_pool_runs_out() {
+    let mut game = limited(&["....", ".*..", "...."]);
+    let founder = game.get_current_player_index();
+
+    // The founder's 5 shares come out of the 25 issued.
+    game.make_move(Point(0, 1));
+    assert_eq!(game.get_company(0).shares_issued, 25);
+    assert_eq!(game.get_company(0).bank_shares, 20);
+
+    assert!(matches!(
+        trade(&mut game, 0, 21),
+        Err(TradeError::TooFewSharesAvailable)
+    ));
+    assert_eq!(game.view().get_phase(), GameState::Trade(0));
+    trade(&mut game, 0, 20).unwrap();
+    assert_eq!(game.get_company(0).bank_shares, 0);
+    assert_eq!(game.get_player(founder).get_holdings(0), 25);
+    check_pool(&game, 0);
+
+    // Growing issues 5 more.
+    next_turn(&mut game);
+    game.make_move(Point(0, 2));
+    assert_eq!(game.get_company(0).shares_issued, 30);
+    assert_eq!(game.get_company(0).bank_shares, 5);
+
+    assert!(matches!(
+        trade(&mut game, 0, 6),
+        Err(TradeError::TooFewSharesAvailable)
+    ));
+    trade(&mut game, 0, 5).unwrap();
+    assert_eq!(game.get_company(0).bank_shares, 0);
+    check_pool(&game, 0);
+
+    // Selling puts shares back in the pool.
+    next_turn(&mut game);
+    game.make_move(Point(2, 3));
+    trade(&mut game, 0, -3).unwrap();
+    assert_eq!(game.get_company(0).bank_shares, 3);
+    assert_eq!(game.get_company(0).shares_issued, 30);
+    assert_eq!(game.get_player(founder).get_holdings(0), 22);
+    check_pool(&game, 0);
+}
+
+#[test]
+fn merges_convert_the_pool() {
+    let mut game = limited(&["....", "*..*", "...."]);
+    let first = game.get_current_player_index();
+
+    // The first player founds A, and the second founds B and buys 3 shares.
+    game.make_move(Point(0, 0));
+    next_turn(&mut game);
+    game.make_move(Point(0, 3));
+    trade(&mut game, 1, 3).unwrap();
+
+    // A grows, and the first player buys 2 shares of B.
+    next_turn(&mut game);
+    game.make_move(Point(0, 1));
+    trade(&mut game, 1, 2).unwrap();
+    assert_eq!(game.get_company(0).bank_shares, 25);
+    assert_eq!(game.get_company(1).bank_shares, 15);
+
+    next_turn(&mut game);
+    game.make_move(Point(2, 1));
+
+    // A is larger, so it absorbs B. The first player's 2 shares of B become 1 of A, the second
+    // player's 8 become 4, and the bank's 15 become 8.
+    next_turn(&mut game);
+    game.make_move(Point(0, 2));
+
+    let a = game.get_company(0);
+    assert_eq!(a.bank_shares, 33);
+    assert_eq!(a.shares_issued, 43);
+    assert_eq!(a.share_price, 1300);
+    assert_eq!(game.get_player(first).get_holdings(0), 6);
+    assert_eq!(game.get_player(1 - first).get_holdings(0), 4);
+    check_pool(&game, 0);
+
+    let b = game.get_company(1);
+    assert!(!b.in_use);
+    assert_eq!(b.bank_shares, 0);
+    assert_eq!(b.shares_issued, 0);
+}
+
+#[test]
+fn splits_double_the_pool() {
+    let mut game = limited(&[".**.", "*..*", ".**."]);
+
+    // Three stars make the price $1,600.
+    game.make_move(Point(1, 1));
+    assert_eq!(game.get_company(0).share_price, 1600);
+    trade(&mut game, 0, 3).unwrap();
+
+    // Growing next to three more stars makes it $3,200, which splits to $1,600.
+    next_turn(&mut game);
+    game.make_move(Point(1, 2));
+
+    let company = game.get_company(0);
+    assert_eq!(company.share_price, 1600);
+    assert_eq!(company.bank_shares, 44);
+    assert_eq!(company.shares_issued, 60);
+    check_pool(&game, 0);
+}
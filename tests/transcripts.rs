@@ -0,0 +1,90 @@
+//! Golden-file tests of the text UI.
+//!
+//! Each test plays a seeded game from a script of inputs and compares the transcript against the
+//! saved one in `tests/transcripts/`. After an intentional change to the UI, regenerate the
+//! transcripts with:
+//!
+//! ```text
+//! UPDATE_TRANSCRIPTS=1 cargo test --test transcripts
+//! ```
+
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Play a scripted game with the given options and check the transcript.
+fn check_transcript(name: &str, args: &[&str]) {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/transcripts");
+    let script = dir.join(format!("{name}.script"));
+    let golden = dir.join(format!("{name}.golden"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_main"))
+        .args(args)
+        .arg("--script")
+        .arg(&script)
+        .output()
+        .expect("failed to run the game");
+
+    assert!(
+        output.status.success(),
+        "game exited with {}",
+        output.status
+    );
+
+    let transcript = String::from_utf8(output.stdout).expect("transcript isn't UTF-8");
+
+    if std::env::var_os("UPDATE_TRANSCRIPTS").is_some() {
+        std::fs::write(&golden, &transcript).expect("failed to write the transcript");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&golden).expect("failed to read the transcript");
+
+    // Find the first difference, since the whole transcript is too much to read.
+    if let Some((line, (got, want))) = transcript
+        .lines()
+        .zip(expected.lines())
+        .enumerate()
+        .find(|(_, (got, want))| got != want)
+    {
+        panic!(
+            "{name}: transcript differs at line {}:\n  got:  {got:?}\n  want: {want:?}",
+            line + 1
+        );
+    }
+
+    assert_eq!(
+        transcript.lines().count(),
+        expected.lines().count(),
+        "{name}: transcript length differs"
+    );
+}
+
+/// Two players with instructions, moves, trades, a merge, splits, and the final standings.
+#[test]
+fn classic_game() {
+    check_transcript(
+        "classic",
+        &["-L", "classic", "--seed", "3", "--max-turns", "24"],
+    );
+}
+
+/// The modern UI against a computer player, with majority bonuses and a merge where the player
+/// picks the survivor.
+#[test]
+fn modern_game() {
+    check_transcript(
+        "modern",
+        &[
+            "--modern",
+            "--seed",
+            "8",
+            "--max-turns",
+            "30",
+            "--majority",
+            "--merge-rule",
+            "choice",
+            "--computer",
+            "3",
+        ],
+    );
+}